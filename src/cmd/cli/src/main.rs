@@ -1,6 +1,6 @@
 #![warn(unused_extern_crates)]
 
-use clap::{command, Args};
+use clap::Args;
 use common::{
     cli::{
         cli_user_interaction_interface::CliUserInteraction,
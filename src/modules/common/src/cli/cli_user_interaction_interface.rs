@@ -4,12 +4,25 @@ use async_trait::async_trait;
 use dialoguer::{
     console::{style, Style},
     theme::ColorfulTheme,
-    Input, Select,
+    Confirm, Input, MultiSelect, Select,
 };
+use std::io::IsTerminal;
 
 pub struct CliUserInteraction {}
 
 impl CliUserInteraction {
+    /// Returns an error if stdin is not a terminal, because the prompts would block forever.
+    fn ensure_terminal(&self, prompt: &str) -> Result<()> {
+        if std::io::stdin().is_terminal() {
+            return Ok(());
+        }
+
+        Err(Error::with_advice(
+            format!("Unable to prompt '{}', because stdin is not a terminal", prompt.trim()),
+            "Use --no-input to accept the default values or provide the answers with --answers-file or --set".into(),
+        ))
+    }
+
     fn get_theme(&self) -> ColorfulTheme {
         ColorfulTheme {
             defaults_style: Style::new().for_stderr().cyan(),
@@ -41,14 +54,20 @@ impl UserInteraction for CliUserInteraction {
     }
 
     async fn print_error(&self, message: &str) {
-        println!("❌ {}", message);
+        eprintln!("❌ {}", message);
     }
 
     async fn print(&self, message: &str) {
         println!("{}", message);
     }
 
+    /// the diagnostics are written to stderr, so that stdout contains only the output (for example a JSON plan)
+    async fn print_diagnostic(&self, message: &str) {
+        eprintln!("{}", message);
+    }
+
     async fn get_input(&self, prompt: &str, default: &str) -> Result<String> {
+        self.ensure_terminal(prompt)?;
         let theme = self.get_theme();
         let default = default.to_string();
         let input = Input::with_theme(&theme)
@@ -60,15 +79,41 @@ impl UserInteraction for CliUserInteraction {
         Ok(input)
     }
 
-    async fn get_selection(&self, prompt: &str, choices: &[String]) -> Result<String> {
+    async fn get_selection(&self, prompt: &str, choices: &[String], default: usize) -> Result<String> {
+        self.ensure_terminal(prompt)?;
         let theme = self.get_theme();
         let selection = Select::with_theme(&theme)
             .with_prompt(prompt)
             .items(choices)
-            .default(0)
+            .default(default)
             .interact()
             .map_err(|e| Error::new(e.to_string()))?;
 
         Ok(choices[selection].to_string())
     }
+
+    async fn get_confirmation(&self, prompt: &str, default: bool) -> Result<bool> {
+        self.ensure_terminal(prompt)?;
+        let theme = self.get_theme();
+        let confirmation = Confirm::with_theme(&theme)
+            .with_prompt(prompt)
+            .default(default)
+            .interact()
+            .map_err(|e| Error::new(e.to_string()))?;
+
+        Ok(confirmation)
+    }
+
+    async fn get_multi_selection(&self, prompt: &str, choices: &[String], defaults: &[bool]) -> Result<Vec<String>> {
+        self.ensure_terminal(prompt)?;
+        let theme = self.get_theme();
+        let selections = MultiSelect::with_theme(&theme)
+            .with_prompt(prompt)
+            .items(choices)
+            .defaults(defaults)
+            .interact()
+            .map_err(|e| Error::new(e.to_string()))?;
+
+        Ok(selections.into_iter().map(|index| choices[index].clone()).collect())
+    }
 }
@@ -1,5 +1,5 @@
 use crate::core::errors::Result;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 #[cfg(test)]
 use mockall::automock;
@@ -14,6 +14,9 @@ pub trait FileSystemInterface: Send + Sync {
     /// move file from source to target
     async fn move_file(&self, source_path: &Path, target_path: &Path) -> Result<()>;
 
+    /// rename (move) the file or folder, the parent folder of the target is created if it does not exist
+    async fn rename(&self, source_path: &Path, target_path: &Path) -> Result<()>;
+
     /// read file
     async fn read_file(&self, path: &Path) -> Result<String>;
 
@@ -23,6 +26,18 @@ pub trait FileSystemInterface: Send + Sync {
     /// read file buffered
     async fn read_file_buffered(&self, path: &Path) -> Result<Vec<String>>;
 
-    /// check if the file is an image
+    /// check if the file is binary (an image for example), only the start of the file is checked
     async fn is_binary(&self, path: &Path) -> Result<bool>;
+
+    /// check if the file or folder exists
+    async fn exists(&self, path: &Path) -> Result<bool>;
+
+    /// check if the folder is empty, a folder which does not exist is empty
+    async fn is_empty_folder(&self, path: &Path) -> Result<bool>;
+
+    /// returns the paths of the files and folders directly in the folder, without their subfolders
+    async fn read_folder(&self, path: &Path) -> Result<Vec<PathBuf>>;
+
+    /// returns the absolute path with all symlinks resolved, the path must exist
+    async fn canonicalize(&self, path: &Path) -> Result<PathBuf>;
 }
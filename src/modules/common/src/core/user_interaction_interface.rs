@@ -8,7 +8,14 @@ pub trait UserInteraction: Send + Sync {
 
     async fn print(&self, message: &str);
 
+    /// print a message beside the output of the command, like a warning or a hint
+    async fn print_diagnostic(&self, message: &str);
+
     async fn get_input(&self, prompt: &str, default: &str) -> Result<String>;
 
-    async fn get_selection(&self, prompt: &str, choices: &[String]) -> Result<String>;
+    async fn get_selection(&self, prompt: &str, choices: &[String], default: usize) -> Result<String>;
+
+    async fn get_confirmation(&self, prompt: &str, default: bool) -> Result<bool>;
+
+    async fn get_multi_selection(&self, prompt: &str, choices: &[String], defaults: &[bool]) -> Result<Vec<String>>;
 }
@@ -1,7 +1,10 @@
 use crate::core::interfaces::FileSystemInterface;
 use crate::core::{errors::Error, errors::Result};
-use std::path::Path;
-use tokio::io::{AsyncBufReadExt, BufReader};
+use std::path::{Path, PathBuf};
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, BufReader};
+
+/// The number of bytes at the start of a file, which are checked to detect a binary file.
+const BINARY_CHECK_LENGTH: u64 = 8192;
 
 #[derive(Default)]
 pub struct FileSystem {}
@@ -35,6 +38,20 @@ impl FileSystemInterface for FileSystem {
         Ok(())
     }
 
+    async fn rename(&self, source_path: &Path, target_path: &Path) -> Result<()> {
+        if let Some(target_dir) = target_path.parent() {
+            tokio::fs::create_dir_all(target_dir)
+                .await
+                .map_err(|e| Error::new(format!("issue to create target directory: {}", e)))?;
+        }
+
+        tokio::fs::rename(source_path, target_path)
+            .await
+            .map_err(|e| Error::new(format!("issue to rename {}: {}", source_path.display(), e)))?;
+
+        Ok(())
+    }
+
     async fn read_file(&self, path: &Path) -> Result<String> {
         let content_bytes = tokio::fs::read(path)
             .await
@@ -76,13 +93,70 @@ impl FileSystemInterface for FileSystem {
     }
 
     async fn is_binary(&self, path: &Path) -> Result<bool> {
-        let res = self.read_file(path).await;
+        // only the start of the file is read, so that large binary files are not loaded into the memory
+        let Ok(file) = tokio::fs::File::open(path).await else {
+            return Ok(true);
+        };
+
+        let mut buffer = vec![];
+        if file.take(BINARY_CHECK_LENGTH).read_to_end(&mut buffer).await.is_err() {
+            return Ok(true);
+        }
+
+        if buffer.contains(&0) {
+            return Ok(true);
+        }
+
+        // a character can be cut at the end of the checked bytes, which is not an invalid character
+        match std::str::from_utf8(&buffer) {
+            Ok(_) => Ok(false),
+            Err(error) => Ok(error.error_len().is_some()),
+        }
+    }
+
+    async fn exists(&self, path: &Path) -> Result<bool> {
+        tokio::fs::try_exists(path)
+            .await
+            .map_err(|e| Error::new(format!("issue to check if path exists: {}", e)))
+    }
 
-        if res.is_err() {
+    async fn is_empty_folder(&self, path: &Path) -> Result<bool> {
+        if !self.exists(path).await? {
             return Ok(true);
         }
 
-        Ok(false)
+        let mut entries = tokio::fs::read_dir(path)
+            .await
+            .map_err(|e| Error::new(format!("issue to read directory: {}", e)))?;
+        let entry = entries
+            .next_entry()
+            .await
+            .map_err(|e| Error::new(format!("issue to read directory: {}", e)))?;
+
+        Ok(entry.is_none())
+    }
+
+    async fn read_folder(&self, path: &Path) -> Result<Vec<PathBuf>> {
+        let mut entries = tokio::fs::read_dir(path)
+            .await
+            .map_err(|e| Error::new(format!("issue to read directory: {}", e)))?;
+
+        let mut paths = vec![];
+        while let Some(entry) = entries
+            .next_entry()
+            .await
+            .map_err(|e| Error::new(format!("issue to read directory: {}", e)))?
+        {
+            paths.push(entry.path());
+        }
+
+        Ok(paths)
+    }
+
+    async fn canonicalize(&self, path: &Path) -> Result<PathBuf> {
+        tokio::fs::canonicalize(path)
+            .await
+            .map_err(|e| Error::new(format!("issue to resolve path {}: {}", path.display(), e)))
     }
 }
 
@@ -151,6 +225,20 @@ mod tests {
         assert!(is_target_file_exists);
     }
 
+    #[tokio::test]
+    async fn test_rename_should_move_file_into_new_folder() {
+        let dir = tempfile::tempdir().unwrap();
+        let source_file_path = dir.path().join("source.txt");
+        let target_file_path = dir.path().join("sub").join("target.txt");
+        tokio::fs::write(&source_file_path, "content").await.unwrap();
+
+        let file_system = FileSystem {};
+        file_system.rename(&source_file_path, &target_file_path).await.unwrap();
+
+        assert!(!source_file_path.exists());
+        assert_eq!(tokio::fs::read_to_string(&target_file_path).await.unwrap(), "content");
+    }
+
     #[tokio::test]
     async fn test_read_file_should_read_file() {
         let dir = tempfile::tempdir().unwrap();
@@ -193,4 +281,68 @@ mod tests {
 
         assert!(is_binary);
     }
+
+    #[tokio::test]
+    async fn test_is_binary_should_return_false_for_large_txt_file_with_cut_character() {
+        let dir = tempfile::tempdir().unwrap();
+        let file_path = dir.path().join("large.txt");
+        let content = format!(
+            "{}ä{}",
+            "a".repeat(BINARY_CHECK_LENGTH as usize - 1),
+            "b".repeat(100_000)
+        );
+        tokio::fs::write(&file_path, content).await.unwrap();
+
+        let file_system = FileSystem {};
+        let is_binary = file_system.is_binary(&file_path).await.unwrap();
+
+        assert!(!is_binary);
+    }
+
+    #[tokio::test]
+    async fn test_is_empty_folder_should_check_entries() {
+        let dir = tempfile::tempdir().unwrap();
+        let file_system = FileSystem {};
+
+        assert!(file_system.is_empty_folder(dir.path()).await.unwrap());
+        assert!(file_system.is_empty_folder(&dir.path().join("missing")).await.unwrap());
+
+        let file_path = dir.path().join("my-temporary-note.txt");
+        tokio::fs::File::create(&file_path).await.unwrap();
+
+        assert!(!file_system.is_empty_folder(dir.path()).await.unwrap());
+        assert!(file_system.exists(&file_path).await.unwrap());
+        assert!(!file_system.exists(&dir.path().join("missing")).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_read_folder_should_return_entries() {
+        let dir = tempfile::tempdir().unwrap();
+        let file_system = FileSystem {};
+        tokio::fs::create_dir_all(dir.path().join("folder")).await.unwrap();
+        tokio::fs::File::create(dir.path().join("folder").join("nested.txt"))
+            .await
+            .unwrap();
+        tokio::fs::File::create(dir.path().join("file.txt")).await.unwrap();
+
+        let mut paths = file_system.read_folder(dir.path()).await.unwrap();
+        paths.sort();
+
+        assert_eq!(paths, vec![dir.path().join("file.txt"), dir.path().join("folder")]);
+    }
+
+    #[tokio::test]
+    async fn test_canonicalize_should_resolve_parent_directories() {
+        let dir = tempfile::tempdir().unwrap();
+        let file_system = FileSystem {};
+        tokio::fs::create_dir_all(dir.path().join("folder")).await.unwrap();
+
+        let path = file_system
+            .canonicalize(&dir.path().join("folder").join(".."))
+            .await
+            .unwrap();
+
+        assert_eq!(path, file_system.canonicalize(dir.path()).await.unwrap());
+        assert!(file_system.canonicalize(&dir.path().join("missing")).await.is_err());
+    }
 }
@@ -3,13 +3,13 @@ use common::cli::command::GroupCommands;
 use common::cli::functions::handle_subcommand;
 use common::core::errors::Result;
 
-use crate::generate::cli::GenerateCliCommand;
+use crate::generate::cli::{GenerateCliCommand, RegenerateCliCommand};
 
 pub struct TemplateGroupCommands {}
 
 impl GroupCommands for TemplateGroupCommands {
     fn get_commands(&self) -> Vec<Box<dyn common::cli::command::Command>> {
-        vec![Box::new(GenerateCliCommand {})]
+        vec![Box::new(GenerateCliCommand {}), Box::new(RegenerateCliCommand {})]
     }
 }
 
@@ -1,17 +1,25 @@
-use clap::{Args, FromArgMatches, Subcommand};
+use clap::{Args, FromArgMatches, Subcommand, ValueEnum};
 
 use common::cli::cli_user_interaction_interface::CliUserInteraction;
 use common::core::errors::{Error, Result};
 use common::{cli::command::Command, infrastructure::file_system::FileSystem};
+use std::collections::HashMap;
 use std::path::PathBuf;
 use std::sync::Arc;
 
+use crate::generate::generation_record::{GenerationRecord, TemplateSource};
 use crate::generate::service::{GenerateProjectInput, GenerateService};
-use crate::templatespecification::core::service::TemplateSpecificationService;
-use crate::templatespecification::core::template_engine::TemplateEngine;
+use crate::templatespecification::core::generation_plan::PlanFormat;
+use crate::templatespecification::core::interfaces::{AnswerProvider, FileListLoader};
+use crate::templatespecification::core::service::{AnswerOptions, TemplateSpecificationService};
+use crate::templatespecification::core::template_engine::{ConflictStrategy, RenderOptions, TemplateEngine};
+use crate::templatespecification::infrastructure::answer_provider::argument_answer_provider::ArgumentAnswerProvider;
+use crate::templatespecification::infrastructure::answer_provider::env_answer_provider::EnvAnswerProvider;
+use crate::templatespecification::infrastructure::answer_provider::file_answer_provider::FileAnswerProvider;
 use crate::templatespecification::infrastructure::configuration_loader::yaml_configuration_loader::YamlConfigurationLoader;
 use crate::templatespecification::infrastructure::folder_loader::git_files_loader::GitFileListLoader;
 use crate::templatespecification::infrastructure::folder_loader::local_file_loader::LocalFileListLoader;
+use crate::templatespecification::infrastructure::user_configuration_loader::YamlUserConfigurationLoader;
 
 /// Represents a command for generating a project from a template.
 pub struct GenerateCliCommand {}
@@ -26,48 +34,41 @@ impl Command for GenerateCliCommand {
         let generate_args = GenerateArgs::from_arg_matches(args)
             .map_err(|e| Error::new(format!("issue to parse generate args: {}", e)))?;
 
-        let file_system = Arc::new(FileSystem {});
-        let cli_interface = Arc::new(CliUserInteraction {});
-        let template_engine = Arc::new(TemplateEngine::new_with_default_template_renderer(
-            file_system,
-            cli_interface.clone(),
-        ));
-
         let sub_command = generate_args.command;
         match sub_command {
             GenerateSubCommands::Local(local_create) => {
+                let template_path = std::fs::canonicalize(&local_create.template_path)
+                    .unwrap_or_else(|_| local_create.template_path.clone());
                 let input = GenerateProjectInput {
                     input_path: Some(local_create.template_path),
                     destination_path: local_create.destination_path,
+                    answer_options: create_answer_options(&local_create.answer_args).await?,
+                    template_source: TemplateSource::Local { path: template_path },
+                    render_options: create_render_options(&local_create.render_args, None),
+                    dry_run: local_create.render_args.dry_run.map(PlanFormat::from),
                 };
 
                 let folder_loader = Arc::new(LocalFileListLoader::default());
-                let configuration_loader = Arc::new(YamlConfigurationLoader::default());
-
-                let template_specification_service = Arc::new(TemplateSpecificationService::new(
-                    folder_loader,
-                    configuration_loader,
-                    cli_interface.clone(),
-                ));
-                let service =
-                    GenerateService::new(template_specification_service, template_engine, cli_interface.clone());
+                let service = create_generate_service(folder_loader);
                 service.generate_project(input).await?;
             }
             GenerateSubCommands::Git(git_create) => {
                 let input: GenerateProjectInput = GenerateProjectInput {
-                    input_path: git_create.input_path,
+                    input_path: git_create.input_path.clone(),
                     destination_path: git_create.destination_path,
+                    answer_options: create_answer_options(&git_create.answer_args).await?,
+                    template_source: TemplateSource::Git {
+                        url: git_create.remote_path.clone(),
+                        branch: git_create.branch.clone(),
+                        commit: None,
+                        input_path: git_create.input_path,
+                    },
+                    render_options: create_render_options(&git_create.render_args, None),
+                    dry_run: git_create.render_args.dry_run.map(PlanFormat::from),
                 };
+
                 let folder_loader = Arc::new(GitFileListLoader::new(git_create.remote_path, git_create.branch));
-                let configuration_loader = Arc::new(YamlConfigurationLoader::default());
-                let template_specification_service = Arc::new(TemplateSpecificationService::new(
-                    folder_loader,
-                    configuration_loader,
-                    cli_interface.clone(),
-                ));
-
-                let service =
-                    GenerateService::new(template_specification_service, template_engine, cli_interface.clone());
+                let service = create_generate_service(folder_loader);
                 service.generate_project(input).await?;
             }
         }
@@ -85,6 +86,140 @@ impl Command for GenerateCliCommand {
     }
 }
 
+/// Represents a command for regenerating a project with the recorded answers.
+pub struct RegenerateCliCommand {}
+
+#[async_trait::async_trait]
+impl Command for RegenerateCliCommand {
+    fn get_name(&self) -> &'static str {
+        "regenerate"
+    }
+
+    async fn execute(&self, args: &clap::ArgMatches) -> Result<()> {
+        let regenerate_args = RegenerateArgs::from_arg_matches(args)
+            .map_err(|e| Error::new(format!("issue to parse regenerate args: {}", e)))?;
+
+        let record = GenerationRecord::load(&FileSystem {}, &regenerate_args.project_path).await?;
+        let destination_path = regenerate_args.destination_path.unwrap_or(regenerate_args.project_path);
+
+        let (folder_loader, input_path): (Arc<dyn FileListLoader + Send + Sync>, Option<PathBuf>) =
+            match record.source.clone() {
+                TemplateSource::Local { path } => (Arc::new(LocalFileListLoader::default()), Some(path)),
+                TemplateSource::Git {
+                    url,
+                    branch,
+                    input_path,
+                    ..
+                } => (Arc::new(GitFileListLoader::new(url, branch)), input_path),
+            };
+
+        let input = GenerateProjectInput {
+            input_path,
+            destination_path,
+            answer_options: AnswerOptions {
+                answer_providers: vec![Arc::new(record.clone())],
+                no_input: regenerate_args.no_input,
+                default_answers: load_default_answers().await?,
+            },
+            template_source: record.source,
+            // the regenerated files replace the files of the project, if nothing else is given
+            render_options: create_render_options(&regenerate_args.render_args, Some(ConflictStrategy::Overwrite)),
+            dry_run: regenerate_args.render_args.dry_run.map(PlanFormat::from),
+        };
+
+        let service = create_generate_service(folder_loader);
+        service.generate_project(input).await?;
+
+        Ok(())
+    }
+
+    fn register_cli(&self, cli: clap::Command) -> clap::Command {
+        let mut regenerate_cli = clap::Command::new(self.get_name())
+            .about("Regenerate a project with the answers recorded in its .creatorly-answers.yml");
+
+        regenerate_cli = RegenerateArgs::augment_args(regenerate_cli);
+        cli.subcommand(regenerate_cli)
+    }
+}
+
+/// Creates the generate service with the given folder loader.
+fn create_generate_service(folder_loader: Arc<dyn FileListLoader + Send + Sync>) -> GenerateService {
+    let cli_interface = Arc::new(CliUserInteraction {});
+    let template_engine = Arc::new(TemplateEngine::new_with_default_template_renderer(
+        Arc::new(FileSystem {}),
+        cli_interface.clone(),
+    ));
+
+    let configuration_loader = Arc::new(YamlConfigurationLoader::default());
+    let template_specification_service = Arc::new(TemplateSpecificationService::new(
+        folder_loader,
+        configuration_loader,
+        cli_interface.clone(),
+    ));
+
+    GenerateService::new(template_specification_service, template_engine, cli_interface)
+}
+
+/// Describes the precedence of the answer sources, it is shown in the help of the generate commands.
+const ANSWER_PRECEDENCE_HELP: &str = "\
+Answer precedence (the first source which provides an answer wins):
+  1. --set KEY=VALUE arguments
+  2. --answers-file
+  3. environment variables CREATORLY_ANSWER_<KEY> (for example CREATORLY_ANSWER_PROJECT_NAME)
+  4. prompt, or the default value with --no-input
+
+The default values of the template are replaced by the default_answers in
+$XDG_CONFIG_HOME/creatorly/config.yml (or ~/.config/creatorly/config.yml).";
+
+/// Creates the answer options from the given arguments.
+/// The order of the answer providers defines the precedence (see `ANSWER_PRECEDENCE_HELP`).
+async fn create_answer_options(answer_args: &AnswerArgs) -> Result<AnswerOptions> {
+    let mut answer_providers: Vec<Arc<dyn AnswerProvider>> = vec![];
+
+    if !answer_args.set.is_empty() {
+        let argument_answer_provider = ArgumentAnswerProvider::from_arguments(&answer_args.set)?;
+        answer_providers.push(Arc::new(argument_answer_provider));
+    }
+
+    // an explicit answers file wins over the ambient environment variables
+    if let Some(answers_file) = &answer_args.answers_file {
+        let file_answer_provider = FileAnswerProvider::load(answers_file).await?;
+        answer_providers.push(Arc::new(file_answer_provider));
+    }
+
+    answer_providers.push(Arc::new(EnvAnswerProvider::from_env()));
+
+    Ok(AnswerOptions {
+        answer_providers,
+        no_input: answer_args.no_input,
+        default_answers: load_default_answers().await?,
+    })
+}
+
+/// Creates the render options from the given arguments.
+/// The default conflict strategy is taken, if neither --on-conflict nor --clean is given.
+fn create_render_options(render_args: &RenderArgs, default_on_conflict: Option<ConflictStrategy>) -> RenderOptions {
+    let on_conflict = match render_args.on_conflict {
+        Some(on_conflict) => Some(on_conflict.into()),
+        None if render_args.clean => None,
+        None => default_on_conflict,
+    };
+
+    RenderOptions {
+        strict: render_args.strict,
+        on_conflict,
+        clean: render_args.clean,
+        jobs: render_args.jobs.map(usize::from),
+    }
+}
+
+/// Loads the default answers from the configuration of the user.
+async fn load_default_answers() -> Result<HashMap<String, String>> {
+    let user_configuration = YamlUserConfigurationLoader::default().load_user_configuration().await?;
+
+    Ok(user_configuration.default_answers.into_iter().collect())
+}
+
 #[derive(Args)]
 struct GenerateArgs {
     #[command(subcommand)]
@@ -94,9 +229,11 @@ struct GenerateArgs {
 #[derive(Subcommand)]
 enum GenerateSubCommands {
     /// Create a new project from a local template
+    #[command(after_help = ANSWER_PRECEDENCE_HELP)]
     Local(GenerateFromLocal),
 
     /// Create a new project from a git repository
+    #[command(after_help = ANSWER_PRECEDENCE_HELP)]
     Git(GenerateFromGit),
 }
 
@@ -109,6 +246,12 @@ struct GenerateFromLocal {
     /// The path to the destination path (it will be created if it does not exist)
     #[arg(short, long)]
     destination_path: PathBuf,
+
+    #[command(flatten)]
+    answer_args: AnswerArgs,
+
+    #[command(flatten)]
+    render_args: RenderArgs,
 }
 
 #[derive(Args)]
@@ -128,4 +271,107 @@ struct GenerateFromGit {
     /// The path to the destination path (it will be created if it does not exist)
     #[arg(short, long)]
     destination_path: PathBuf,
+
+    #[command(flatten)]
+    answer_args: AnswerArgs,
+
+    #[command(flatten)]
+    render_args: RenderArgs,
+}
+
+#[derive(Args)]
+struct AnswerArgs {
+    /// The path to a YAML or JSON file with answers, these placeholders will not be prompted
+    #[arg(long)]
+    answers_file: Option<PathBuf>,
+
+    /// Answer a placeholder with the given value (can be repeated), it takes precedence over the answers file
+    #[arg(long, value_name = "KEY=VALUE")]
+    set: Vec<String>,
+
+    /// Do not prompt, take the default value (or the first choice) for every placeholder without an answer
+    #[arg(long)]
+    no_input: bool,
+}
+
+#[derive(Args)]
+struct RenderArgs {
+    /// Fail instead of warn, if the template uses placeholders without an answer (for example a typo) or a file can
+    /// not be rendered
+    #[arg(long)]
+    strict: bool,
+
+    /// What happens with files, which exist already in the destination (without it a destination with files is refused)
+    #[arg(long, value_enum)]
+    on_conflict: Option<OnConflict>,
+
+    /// Delete the destination before the files are generated
+    #[arg(long, conflicts_with = "on_conflict")]
+    clean: bool,
+
+    /// Print the files, which would be generated, as tree or JSON without writing anything
+    #[arg(long, value_enum, value_name = "FORMAT", num_args = 0..=1, default_missing_value = "tree")]
+    dry_run: Option<DryRunFormat>,
+
+    /// Number of files, which are rendered at the same time (default: number of CPUs)
+    #[arg(long, value_name = "N", value_parser = clap::value_parser!(u16).range(1..))]
+    jobs: Option<u16>,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum DryRunFormat {
+    /// The target files as a tree with their source
+    Tree,
+    /// The files as JSON with source, target, action and conflict
+    Json,
+}
+
+impl From<DryRunFormat> for PlanFormat {
+    fn from(dry_run_format: DryRunFormat) -> Self {
+        match dry_run_format {
+            DryRunFormat::Tree => PlanFormat::Tree,
+            DryRunFormat::Json => PlanFormat::Json,
+        }
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum OnConflict {
+    /// Keep the existing file
+    Skip,
+    /// Replace the existing file
+    Overwrite,
+    /// Ask for each existing file
+    Prompt,
+    /// Fail before any file is written
+    Fail,
+}
+
+impl From<OnConflict> for ConflictStrategy {
+    fn from(on_conflict: OnConflict) -> Self {
+        match on_conflict {
+            OnConflict::Skip => ConflictStrategy::Skip,
+            OnConflict::Overwrite => ConflictStrategy::Overwrite,
+            OnConflict::Prompt => ConflictStrategy::Prompt,
+            OnConflict::Fail => ConflictStrategy::Fail,
+        }
+    }
+}
+
+#[derive(Args)]
+struct RegenerateArgs {
+    /// The path to the generated project, which contains the .creatorly-answers.yml
+    #[arg(short, long, default_value = ".")]
+    project_path: PathBuf,
+
+    /// The path to the destination path, if not specified, the project path will be used
+    #[arg(short, long)]
+    destination_path: Option<PathBuf>,
+
+    /// Do not prompt for placeholders, which are not recorded, take their default value (or the first choice)
+    #[arg(long)]
+    no_input: bool,
+
+    #[command(flatten)]
+    render_args: RenderArgs,
 }
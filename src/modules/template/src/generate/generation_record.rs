@@ -0,0 +1,140 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use common::core::errors::{Error, Result};
+use common::core::interfaces::FileSystemInterface;
+use serde::{Deserialize, Serialize};
+
+use crate::templatespecification::core::answer::Answer;
+use crate::templatespecification::core::interfaces::AnswerProvider;
+
+/// Represents the source of a template.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum TemplateSource {
+    /// A template from the local file system.
+    Local { path: PathBuf },
+
+    /// A template from a git repository.
+    Git {
+        url: String,
+        branch: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        commit: Option<String>,
+        input_path: Option<PathBuf>,
+    },
+}
+
+/// The record of a generation, it is stored in the generated project to replay the generation.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct GenerationRecord {
+    /// The version of creatorly, which generated the project.
+    pub creatorly_version: String,
+
+    /// The source of the template.
+    pub source: TemplateSource,
+
+    /// The answers of the placeholders.
+    pub answers: BTreeMap<String, Answer>,
+}
+
+impl GenerationRecord {
+    /// The name of the file, which is written into the generated project.
+    pub const FILE_NAME: &'static str = ".creatorly-answers.yml";
+
+    pub fn new(source: TemplateSource, answers: BTreeMap<String, Answer>) -> Self {
+        Self {
+            creatorly_version: env!("CARGO_PKG_VERSION").to_string(),
+            source,
+            answers,
+        }
+    }
+
+    /// Loads the record from the given project directory.
+    pub async fn load(file_system: &dyn FileSystemInterface, project_path: &Path) -> Result<Self> {
+        let path = project_path.join(Self::FILE_NAME);
+        let content = file_system.read_file(&path).await.map_err(|e| {
+            Error::with_advice(
+                format!("Unable to read {}: {}", path.display(), e),
+                "The project has to be generated with creatorly first".into(),
+            )
+        })?;
+
+        serde_yaml::from_str(&content).map_err(|e| Error::new(format!("Unable to parse {}: {}", path.display(), e)))
+    }
+
+    /// Returns the content of the record file.
+    pub fn to_yaml(&self) -> Result<String> {
+        serde_yaml::to_string(self).map_err(|e| Error::new(format!("Unable to serialize answers: {}", e)))
+    }
+}
+
+impl AnswerProvider for GenerationRecord {
+    fn get_source_name(&self) -> String {
+        format!("recorded answers {}", Self::FILE_NAME)
+    }
+
+    fn get_answer(&self, key: &str) -> Option<String> {
+        self.answers.get(key).map(Answer::to_string)
+    }
+
+    fn get_keys(&self) -> Vec<String> {
+        self.answers.keys().cloned().collect()
+    }
+
+    fn is_record(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::infrastructure::file_system::FileSystem;
+
+    #[tokio::test]
+    async fn test_load_should_return_written_record() {
+        let dir = tempfile::tempdir().unwrap();
+        let file_system = FileSystem {};
+        let source = TemplateSource::Git {
+            url: "https://github.com/klab365/creatorly.git".to_string(),
+            branch: "main".to_string(),
+            commit: Some("0123456789abcdef".to_string()),
+            input_path: Some(PathBuf::from("/tmp")),
+        };
+        let answers = BTreeMap::from([
+            ("project_name".to_string(), Answer::from("MyProject")),
+            (
+                "features".to_string(),
+                Answer::List(vec!["logging".to_string(), "metrics".to_string()]),
+            ),
+        ]);
+        let record = GenerationRecord::new(source, answers);
+
+        file_system
+            .write_file(
+                &dir.path().join(GenerationRecord::FILE_NAME),
+                &record.to_yaml().unwrap(),
+            )
+            .await
+            .unwrap();
+        let loaded_record = GenerationRecord::load(&file_system, dir.path()).await.unwrap();
+
+        assert_eq!(loaded_record, record);
+        assert_eq!(loaded_record.get_answer("project_name"), Some("MyProject".to_string()));
+        assert_eq!(
+            loaded_record.get_answer("features"),
+            Some("logging, metrics".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_load_should_return_error_if_file_does_not_exist() {
+        let dir = tempfile::tempdir().unwrap();
+        let file_system = FileSystem {};
+
+        let result = GenerationRecord::load(&file_system, dir.path()).await;
+
+        assert!(result.is_err());
+    }
+}
@@ -1,3 +1,4 @@
+pub mod generation_record;
 pub mod service;
 
 #[cfg(feature = "cli")]
@@ -1,12 +1,15 @@
-use std::path::PathBuf;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use common::core::errors::{Error, Result};
 use common::core::user_interaction_interface::UserInteraction;
 
-use crate::templatespecification::core::service::TemplateSpecificationService;
-use crate::templatespecification::core::template_configuration::TemplateConfiguration;
-use crate::templatespecification::core::template_engine::{RenderPushArgument, TemplateEngine};
+use crate::generate::generation_record::{GenerationRecord, TemplateSource};
+use crate::templatespecification::core::answer::Answer;
+use crate::templatespecification::core::generation_plan::PlanFormat;
+use crate::templatespecification::core::service::{AnswerOptions, TemplateSpecificationService};
+use crate::templatespecification::core::template_engine::{RenderOptions, RenderPushArgument, TemplateEngine};
 
 /// Represents the input parameters for generating a project.
 pub struct GenerateProjectInput {
@@ -14,6 +17,14 @@ pub struct GenerateProjectInput {
     pub input_path: Option<PathBuf>,
     /// The path where the generated project will be saved.
     pub destination_path: PathBuf,
+    /// The options, which define how the answers are resolved.
+    pub answer_options: AnswerOptions,
+    /// The source of the template, it is recorded in the generated project.
+    pub template_source: TemplateSource,
+    /// The options, which define how the files are rendered and written.
+    pub render_options: RenderOptions,
+    /// Print the plan of the generation in the given format instead of writing the files.
+    pub dry_run: Option<PlanFormat>,
 }
 
 /// Structure for the create service
@@ -38,7 +49,7 @@ impl GenerateService {
 
     /// Create a project from a given template
     pub async fn generate_project(&self, input: GenerateProjectInput) -> Result<()> {
-        let input_path = input.input_path;
+        let input_path = input.input_path.clone();
         let Some(input_path) = input_path else {
             return Err(Error::new("Input path is required".into()));
         };
@@ -49,22 +60,48 @@ impl GenerateService {
             .load_template_configuration(&input_path)
             .await?;
 
-        let found_configurations = template_configuration.templates.len();
-        let msg = format!("found {} creatorly.yml files", found_configurations);
-        self.user_interaction_interface.print_success(&msg).await;
+        // the output of a dry run in JSON is only the plan, so that it can be parsed
+        let is_quiet = input.dry_run == Some(PlanFormat::Json);
+
+        if !is_quiet {
+            let found_configurations = template_configuration.templates.len();
+            let msg = format!("found {} creatorly.yml files", found_configurations);
+            self.user_interaction_interface.print_success(&msg).await;
+            self.user_interaction_interface
+                .print("📝 fill answer for questions")
+                .await;
+        }
 
         // parse answer for question
-        self.parse_answer_for_questions(&mut template_configuration).await?;
+        self.template_specification_service
+            .get_answers(&mut template_configuration, &input.answer_options)
+            .await?;
 
-        // render files and push it to the destination folder
-        self.user_interaction_interface
-            .print("🚀 Render files and copy it to the destination folder")
-            .await;
-        let args = RenderPushArgument {
+        let answers = template_configuration.answers.clone();
+        let mut args = RenderPushArgument {
             input_path: input_path.clone(),
             destination_path: input.destination_path.clone(),
             template_configuration,
+            options: input.render_options.clone(),
+            additional_files: vec![],
         };
+
+        // the record is written together with the rendered files, so that both are kept or discarded together
+        let record = self.create_generation_record(&input_path, &input, answers).await?;
+        args.additional_files
+            .push((PathBuf::from(GenerationRecord::FILE_NAME), record.to_yaml()?));
+
+        // print the plan without writing the files
+        if let Some(format) = input.dry_run {
+            let plan = self.template_engine.plan(&args).await?;
+            self.user_interaction_interface.print(&plan.format(format)?).await;
+            return Ok(());
+        }
+
+        // render files and push it to the destination folder
+        self.user_interaction_interface
+            .print("🚀 Render files and copy it to the destination folder")
+            .await;
         self.template_engine.render_and_push(args).await?;
 
         let success_msg = format!(
@@ -76,15 +113,126 @@ impl GenerateService {
         Ok(())
     }
 
-    async fn parse_answer_for_questions(&self, template_configuration: &mut TemplateConfiguration) -> Result<()> {
-        self.user_interaction_interface
-            .print("📝 fill answer for questions")
-            .await;
+    /// create the record of the source and the answers for the generated project, so that the generation can be replayed
+    async fn create_generation_record(
+        &self,
+        input_path: &Path,
+        input: &GenerateProjectInput,
+        answers: impl IntoIterator<Item = (String, Answer)>,
+    ) -> Result<GenerationRecord> {
+        let mut source = input.template_source.clone();
+        if let TemplateSource::Git { commit, .. } = &mut source {
+            *commit = self
+                .template_specification_service
+                .get_template_revision(input_path)
+                .await?;
+        }
 
-        self.template_specification_service
-            .get_answers(template_configuration)
-            .await?;
+        Ok(GenerationRecord::new(source, BTreeMap::from_iter(answers)))
+    }
+}
 
-        Ok(())
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::templatespecification::infrastructure::configuration_loader::yaml_configuration_loader::YamlConfigurationLoader;
+    use crate::templatespecification::infrastructure::folder_loader::local_file_loader::LocalFileListLoader;
+    use async_trait::async_trait;
+    use common::infrastructure::file_system::FileSystem;
+    use mockall::mock;
+    use std::collections::HashMap;
+    use std::fs;
+    use std::sync::Mutex;
+    use tempdir::TempDir;
+
+    mock! {
+        UserInteractionInterface {}
+
+        #[async_trait]
+        impl UserInteraction for UserInteractionInterface {
+            async fn print_success(&self, message: &str);
+
+            async fn print_error(&self, message: &str);
+
+            async fn print(&self, message: &str);
+
+            async fn print_diagnostic(&self, message: &str);
+
+            async fn get_input(&self, prompt: &str, default: &str) -> Result<String>;
+
+            async fn get_selection(&self, prompt: &str, choices: &[String], default: usize) -> Result<String>;
+
+            async fn get_confirmation(&self, prompt: &str, default: bool) -> Result<bool>;
+
+            async fn get_multi_selection(&self, prompt: &str, choices: &[String], defaults: &[bool]) -> Result<Vec<String>>;
+        }
+    }
+
+    #[tokio::test]
+    async fn test_generate_project_should_print_only_json_for_dry_run_json() {
+        // arrange
+        let temp_dir = TempDir::new("test_dry_run_json").unwrap();
+        let input_path = temp_dir.path().join("template");
+        fs::create_dir_all(&input_path).unwrap();
+        fs::write(
+            input_path.join("creatorly.yml"),
+            "placeholders:\n  name: Max\n  name_full: Max Muster\n",
+        )
+        .unwrap();
+        fs::write(input_path.join("CREATORLY.name.txt"), "CREATORLY.name_full").unwrap();
+
+        let outputs = Arc::new(Mutex::new(vec![]));
+        let mut user_interaction = MockUserInteractionInterface::new();
+        let captured_outputs = outputs.clone();
+        user_interaction
+            .expect_print()
+            .returning(move |message| captured_outputs.lock().unwrap().push(message.to_string()));
+        user_interaction
+            .expect_print_diagnostic()
+            .withf(|message| message.starts_with("Warning: "))
+            .times(1)
+            .returning(|_| ());
+        let user_interaction: Arc<dyn UserInteraction> = Arc::new(user_interaction);
+
+        let sut = GenerateService::new(
+            Arc::new(TemplateSpecificationService::new(
+                Arc::new(LocalFileListLoader::default()),
+                Arc::new(YamlConfigurationLoader::default()),
+                user_interaction.clone(),
+            )),
+            Arc::new(TemplateEngine::new_with_default_template_renderer(
+                Arc::new(FileSystem {}),
+                user_interaction.clone(),
+            )),
+            user_interaction,
+        );
+        let input = GenerateProjectInput {
+            input_path: Some(input_path.clone()),
+            destination_path: temp_dir.path().join("out"),
+            answer_options: AnswerOptions {
+                answer_providers: vec![],
+                no_input: true,
+                default_answers: HashMap::new(),
+            },
+            template_source: TemplateSource::Local { path: input_path },
+            render_options: RenderOptions::default(),
+            dry_run: Some(PlanFormat::Json),
+        };
+
+        // act
+        sut.generate_project(input).await.unwrap();
+
+        // assert
+        let outputs = outputs.lock().unwrap();
+        assert_eq!(outputs.len(), 1);
+        let plan: serde_json::Value = serde_json::from_str(&outputs[0]).unwrap();
+        assert_eq!(plan["files"].as_array().unwrap().len(), 2);
+        assert_eq!(plan["files"][0]["source"], "CREATORLY.name.txt");
+        assert_eq!(
+            plan["files"][1]["target"],
+            temp_dir.path().join("out/.creatorly-answers.yml").to_str().unwrap()
+        );
+        assert_eq!(plan["files"][1]["action"], "write");
+        assert!(!temp_dir.path().join("out").exists());
     }
 }
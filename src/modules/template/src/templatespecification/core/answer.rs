@@ -0,0 +1,76 @@
+use std::fmt::{self, Display};
+
+use serde::{Deserialize, Serialize};
+
+/// The answer of a placeholder. It is a single value or a list of values, for example the entries of a multi choice.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum Answer {
+    /// A single value.
+    Single(String),
+
+    /// A list of values.
+    List(Vec<String>),
+}
+
+impl Answer {
+    /// Returns the entries of the answer. A single value is a list with one entry, or an empty list if it is empty.
+    pub fn get_entries(&self) -> Vec<String> {
+        match self {
+            Self::Single(value) if value.is_empty() => vec![],
+            Self::Single(value) => vec![value.clone()],
+            Self::List(values) => values.clone(),
+        }
+    }
+}
+
+/// The answer is displayed as it is rendered, the entries of a list are separated by `, `.
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Single(value) => write!(f, "{}", value),
+            Self::List(values) => write!(f, "{}", values.join(", ")),
+        }
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Self::Single(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Self::Single(value.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_answer_should_be_displayed_and_split_into_entries() {
+        let single = Answer::from("web");
+        let empty = Answer::from("");
+        let list = Answer::List(vec!["web".to_string(), "db".to_string()]);
+
+        assert_eq!(single.to_string(), "web");
+        assert_eq!(list.to_string(), "web, db");
+        assert_eq!(single.get_entries(), vec!["web"]);
+        assert!(empty.get_entries().is_empty());
+        assert_eq!(list.get_entries(), vec!["web", "db"]);
+    }
+
+    #[test]
+    fn test_answer_should_be_serialized_as_value_or_sequence() {
+        let list = Answer::List(vec!["web".to_string(), "db".to_string()]);
+
+        let yaml = serde_yaml::to_string(&list).unwrap();
+
+        assert_eq!(yaml, "- web\n- db\n");
+        assert_eq!(serde_yaml::from_str::<Answer>(&yaml).unwrap(), list);
+        assert_eq!(serde_yaml::from_str::<Answer>("web").unwrap(), Answer::from("web"));
+    }
+}
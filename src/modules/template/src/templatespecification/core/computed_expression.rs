@@ -0,0 +1,191 @@
+use std::collections::HashMap;
+
+use indexmap::IndexMap;
+
+use super::answer::Answer;
+use super::filters::{apply_filter, check_filter};
+
+/// An expression, which computes the value of a placeholder from other answers.
+///
+/// The placeholders are written in double curly braces and can be converted by filters, for example
+/// `{{ project_name | kebab }}-cli`. The available filters are listed in `FILTER_NAMES`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ComputedExpression {
+    parts: Vec<ExpressionPart>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum ExpressionPart {
+    Text(String),
+    Placeholder { key: String, filters: Vec<String> },
+}
+
+impl ComputedExpression {
+    /// Parses the given expression.
+    pub fn parse(expression: &str) -> Result<Self, String> {
+        let mut parts = vec![];
+        let mut rest = expression;
+
+        while let Some(start) = rest.find("{{") {
+            if start > 0 {
+                parts.push(ExpressionPart::Text(rest[..start].to_string()));
+            }
+
+            let Some(end) = rest[start..].find("}}") else {
+                return Err(format!("Missing closing '}}}}' in expression '{}'", expression));
+            };
+
+            let mut segments = rest[start + 2..start + end].split('|').map(|segment| segment.trim());
+            let key = segments.next().unwrap_or_default().to_string();
+            if key.is_empty() {
+                return Err(format!("Missing placeholder in expression '{}'", expression));
+            }
+
+            let filters: Vec<String> = segments.map(|filter| filter.to_string()).collect();
+            for filter in &filters {
+                check_filter(filter)?;
+            }
+
+            parts.push(ExpressionPart::Placeholder { key, filters });
+            rest = &rest[start + end + 2..];
+        }
+
+        if !rest.is_empty() {
+            parts.push(ExpressionPart::Text(rest.to_string()));
+        }
+
+        Ok(Self { parts })
+    }
+
+    /// Computes the value with the given answers. A placeholder without an answer is an empty string.
+    pub fn evaluate(&self, answers: &HashMap<String, Answer>) -> String {
+        let mut value = String::new();
+
+        for part in &self.parts {
+            match part {
+                ExpressionPart::Text(text) => value.push_str(text),
+                ExpressionPart::Placeholder { key, filters } => {
+                    let answer = answers.get(key).map(Answer::to_string).unwrap_or_default();
+                    let answer = filters.iter().fold(answer, |answer, filter| {
+                        apply_filter(filter, &answer).expect("filters are checked while parsing")
+                    });
+                    value.push_str(&answer);
+                }
+            }
+        }
+
+        value
+    }
+
+    /// Returns the keys of the placeholders, which are used in the expression.
+    pub fn get_placeholders(&self) -> Vec<String> {
+        self.parts
+            .iter()
+            .filter_map(|part| match part {
+                ExpressionPart::Placeholder { key, .. } => Some(key.clone()),
+                ExpressionPart::Text(_) => None,
+            })
+            .collect()
+    }
+}
+
+/// Sorts the keys of the computed placeholders, so that every placeholder comes after the computed placeholders it
+/// depends on. It returns an error, if the computed placeholders depend on each other in a cycle.
+pub fn sort_by_dependencies(expressions: &IndexMap<String, ComputedExpression>) -> Result<Vec<String>, String> {
+    let mut sorted_keys = vec![];
+    let mut path = vec![];
+
+    for key in expressions.keys() {
+        visit(key, expressions, &mut path, &mut sorted_keys)?;
+    }
+
+    Ok(sorted_keys)
+}
+
+fn visit(
+    key: &str,
+    expressions: &IndexMap<String, ComputedExpression>,
+    path: &mut Vec<String>,
+    sorted_keys: &mut Vec<String>,
+) -> Result<(), String> {
+    if sorted_keys.iter().any(|sorted_key| sorted_key == key) {
+        return Ok(());
+    }
+
+    if let Some(position) = path.iter().position(|visited_key| visited_key == key) {
+        let mut cycle = path[position..].to_vec();
+        cycle.push(key.to_string());
+        return Err(format!(
+            "The computed placeholders depend on each other: {}",
+            cycle.join(" -> ")
+        ));
+    }
+
+    let Some(expression) = expressions.get(key) else {
+        return Ok(());
+    };
+
+    path.push(key.to_string());
+    for dependency in expression.get_placeholders() {
+        visit(&dependency, expressions, path, sorted_keys)?;
+    }
+    path.pop();
+
+    sorted_keys.push(key.to_string());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_evaluate_should_apply_filters() {
+        let expression =
+            ComputedExpression::parse("{{ project_name | kebab }}-cli ({{project_name|pascal|upper}})").unwrap();
+        let answers = HashMap::from([("project_name".to_string(), Answer::from("My Project"))]);
+
+        assert_eq!(expression.evaluate(&answers), "my-project-cli (MYPROJECT)");
+        assert_eq!(expression.get_placeholders(), vec!["project_name", "project_name"]);
+    }
+
+    #[test]
+    fn test_parse_should_return_error_for_invalid_expression() {
+        assert!(ComputedExpression::parse("{{ project_name | kebab").is_err());
+        assert!(ComputedExpression::parse("{{ | kebab }}").is_err());
+        assert!(ComputedExpression::parse("{{ project_name | reverse }}").is_err());
+    }
+
+    #[test]
+    fn test_sort_by_dependencies_should_order_dependencies_first() {
+        let expressions = IndexMap::from([
+            (
+                "crate_name".to_string(),
+                ComputedExpression::parse("{{ slug | snake }}").unwrap(),
+            ),
+            (
+                "slug".to_string(),
+                ComputedExpression::parse("{{ project_name | kebab }}").unwrap(),
+            ),
+        ]);
+
+        let sorted_keys = sort_by_dependencies(&expressions).unwrap();
+
+        assert_eq!(sorted_keys, vec!["slug", "crate_name"]);
+    }
+
+    #[test]
+    fn test_sort_by_dependencies_should_return_error_for_cycle() {
+        let expressions = IndexMap::from([
+            ("a".to_string(), ComputedExpression::parse("{{ b }}").unwrap()),
+            ("b".to_string(), ComputedExpression::parse("{{ a }}").unwrap()),
+        ]);
+
+        let result = sort_by_dependencies(&expressions);
+
+        assert_eq!(
+            result,
+            Err("The computed placeholders depend on each other: a -> b -> a".to_string())
+        );
+    }
+}
@@ -0,0 +1,291 @@
+use std::collections::HashMap;
+
+use super::answer::Answer;
+
+/// A condition over the answers, for example `ci_provider == 'gitlab' and use_docker`.
+///
+/// Supported are the comparisons `==` and `!=`, the logical operators `and`, `or` and `not` (or `&&`, `||` and `!`),
+/// parentheses, quoted strings, numbers, `true`/`false` and the keys of placeholders.
+/// A single value is true, if it is not empty and not `false`. A placeholder without an answer is an empty string.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Condition {
+    expression: Expression,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Expression {
+    Literal(String),
+    Placeholder(String),
+    Equal(Box<Expression>, Box<Expression>),
+    NotEqual(Box<Expression>, Box<Expression>),
+    And(Box<Expression>, Box<Expression>),
+    Or(Box<Expression>, Box<Expression>),
+    Not(Box<Expression>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Identifier(String),
+    Literal(String),
+    Equal,
+    NotEqual,
+    And,
+    Or,
+    Not,
+    OpenParenthesis,
+    CloseParenthesis,
+}
+
+impl Condition {
+    /// Parses the given condition.
+    pub fn parse(condition: &str) -> Result<Self, String> {
+        let tokens = tokenize(condition)?;
+        let mut parser = Parser { tokens, position: 0 };
+
+        let expression = parser.parse_or()?;
+        if let Some(token) = parser.peek() {
+            return Err(format!("Unexpected token {:?} in condition '{}'", token, condition));
+        }
+
+        Ok(Self { expression })
+    }
+
+    /// Evaluates the condition with the given answers.
+    pub fn evaluate(&self, answers: &HashMap<String, Answer>) -> bool {
+        is_true(&self.expression.evaluate(answers))
+    }
+
+    /// Returns the keys of the placeholders, which are used in the condition.
+    pub fn get_placeholders(&self) -> Vec<String> {
+        let mut placeholders = vec![];
+        self.expression.collect_placeholders(&mut placeholders);
+        placeholders
+    }
+}
+
+impl Expression {
+    fn evaluate(&self, answers: &HashMap<String, Answer>) -> String {
+        match self {
+            Self::Literal(value) => value.clone(),
+            Self::Placeholder(key) => answers.get(key).map(Answer::to_string).unwrap_or_default(),
+            Self::Equal(left, right) => (left.evaluate(answers) == right.evaluate(answers)).to_string(),
+            Self::NotEqual(left, right) => (left.evaluate(answers) != right.evaluate(answers)).to_string(),
+            Self::And(left, right) => {
+                (is_true(&left.evaluate(answers)) && is_true(&right.evaluate(answers))).to_string()
+            }
+            Self::Or(left, right) => {
+                (is_true(&left.evaluate(answers)) || is_true(&right.evaluate(answers))).to_string()
+            }
+            Self::Not(expression) => (!is_true(&expression.evaluate(answers))).to_string(),
+        }
+    }
+
+    fn collect_placeholders(&self, placeholders: &mut Vec<String>) {
+        match self {
+            Self::Literal(_) => {}
+            Self::Placeholder(key) => placeholders.push(key.clone()),
+            Self::Equal(left, right) | Self::NotEqual(left, right) | Self::And(left, right) | Self::Or(left, right) => {
+                left.collect_placeholders(placeholders);
+                right.collect_placeholders(placeholders);
+            }
+            Self::Not(expression) => expression.collect_placeholders(placeholders),
+        }
+    }
+}
+
+/// Returns true if the value is not empty and not `false`.
+pub fn is_true(value: &str) -> bool {
+    !value.is_empty() && value != "false"
+}
+
+fn tokenize(condition: &str) -> Result<Vec<Token>, String> {
+    let mut tokens = vec![];
+    let mut chars = condition.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            c if c.is_whitespace() => {}
+            '(' => tokens.push(Token::OpenParenthesis),
+            ')' => tokens.push(Token::CloseParenthesis),
+            '=' if chars.next_if_eq(&'=').is_some() => tokens.push(Token::Equal),
+            '!' if chars.next_if_eq(&'=').is_some() => tokens.push(Token::NotEqual),
+            '!' => tokens.push(Token::Not),
+            '&' if chars.next_if_eq(&'&').is_some() => tokens.push(Token::And),
+            '|' if chars.next_if_eq(&'|').is_some() => tokens.push(Token::Or),
+            '\'' | '"' => {
+                let mut literal = String::new();
+                loop {
+                    match chars.next() {
+                        Some(next) if next == c => break,
+                        Some(next) => literal.push(next),
+                        None => return Err(format!("Unterminated string in condition '{}'", condition)),
+                    }
+                }
+
+                tokens.push(Token::Literal(literal));
+            }
+            c if c.is_alphanumeric() || c == '_' || c == '-' || c == '.' => {
+                let mut word = c.to_string();
+                while let Some(next) =
+                    chars.next_if(|next| next.is_alphanumeric() || *next == '_' || *next == '-' || *next == '.')
+                {
+                    word.push(next);
+                }
+
+                let token = match word.as_str() {
+                    "and" => Token::And,
+                    "or" => Token::Or,
+                    "not" => Token::Not,
+                    "true" | "false" => Token::Literal(word),
+                    _ if word.parse::<f64>().is_ok() => Token::Literal(word),
+                    _ => Token::Identifier(word),
+                };
+
+                tokens.push(token);
+            }
+            _ => return Err(format!("Unexpected character '{}' in condition '{}'", c, condition)),
+        }
+    }
+
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    position: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.position).cloned();
+        self.position += 1;
+        token
+    }
+
+    fn next_if(&mut self, token: &Token) -> bool {
+        if self.peek() == Some(token) {
+            self.position += 1;
+            return true;
+        }
+
+        false
+    }
+
+    fn parse_or(&mut self) -> Result<Expression, String> {
+        let mut expression = self.parse_and()?;
+        while self.next_if(&Token::Or) {
+            expression = Expression::Or(Box::new(expression), Box::new(self.parse_and()?));
+        }
+
+        Ok(expression)
+    }
+
+    fn parse_and(&mut self) -> Result<Expression, String> {
+        let mut expression = self.parse_not()?;
+        while self.next_if(&Token::And) {
+            expression = Expression::And(Box::new(expression), Box::new(self.parse_not()?));
+        }
+
+        Ok(expression)
+    }
+
+    fn parse_not(&mut self) -> Result<Expression, String> {
+        if self.next_if(&Token::Not) {
+            return Ok(Expression::Not(Box::new(self.parse_not()?)));
+        }
+
+        self.parse_comparison()
+    }
+
+    fn parse_comparison(&mut self) -> Result<Expression, String> {
+        let left = self.parse_operand()?;
+        if self.next_if(&Token::Equal) {
+            return Ok(Expression::Equal(Box::new(left), Box::new(self.parse_operand()?)));
+        }
+
+        if self.next_if(&Token::NotEqual) {
+            return Ok(Expression::NotEqual(Box::new(left), Box::new(self.parse_operand()?)));
+        }
+
+        Ok(left)
+    }
+
+    fn parse_operand(&mut self) -> Result<Expression, String> {
+        match self.next() {
+            Some(Token::Identifier(key)) => Ok(Expression::Placeholder(key)),
+            Some(Token::Literal(value)) => Ok(Expression::Literal(value)),
+            Some(Token::OpenParenthesis) => {
+                let expression = self.parse_or()?;
+                if !self.next_if(&Token::CloseParenthesis) {
+                    return Err("Missing closing parenthesis in condition".to_string());
+                }
+
+                Ok(expression)
+            }
+            Some(token) => Err(format!("Unexpected token {:?} in condition", token)),
+            None => Err("Unexpected end of condition".to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn answers() -> HashMap<String, Answer> {
+        HashMap::from([
+            ("ci_provider".to_string(), Answer::from("gitlab")),
+            ("use_docker".to_string(), Answer::from("false")),
+            ("port".to_string(), Answer::from("8080")),
+        ])
+    }
+
+    #[test]
+    fn test_evaluate_should_compare_values() {
+        let answers = answers();
+
+        assert!(Condition::parse("ci_provider == 'gitlab'").unwrap().evaluate(&answers));
+        assert!(!Condition::parse("ci_provider == \"github\"")
+            .unwrap()
+            .evaluate(&answers));
+        assert!(Condition::parse("ci_provider != 'github'").unwrap().evaluate(&answers));
+        assert!(Condition::parse("port == 8080").unwrap().evaluate(&answers));
+    }
+
+    #[test]
+    fn test_evaluate_should_combine_expressions() {
+        let answers = answers();
+
+        assert!(!Condition::parse("use_docker").unwrap().evaluate(&answers));
+        assert!(Condition::parse("not use_docker").unwrap().evaluate(&answers));
+        assert!(Condition::parse("!use_docker && ci_provider == 'gitlab'")
+            .unwrap()
+            .evaluate(&answers));
+        assert!(
+            Condition::parse("use_docker or (ci_provider == 'gitlab' and port != '80')")
+                .unwrap()
+                .evaluate(&answers)
+        );
+        assert!(!Condition::parse("unknown").unwrap().evaluate(&answers));
+    }
+
+    #[test]
+    fn test_get_placeholders_should_return_used_keys() {
+        let condition = Condition::parse("ci_provider == 'gitlab' and not use_docker").unwrap();
+
+        assert_eq!(condition.get_placeholders(), vec!["ci_provider", "use_docker"]);
+    }
+
+    #[test]
+    fn test_parse_should_return_error_for_invalid_condition() {
+        assert!(Condition::parse("ci_provider == ").is_err());
+        assert!(Condition::parse("ci_provider == 'gitlab").is_err());
+        assert!(Condition::parse("(use_docker").is_err());
+        assert!(Condition::parse("use_docker use_docker").is_err());
+        assert!(Condition::parse("ci_provider = 'gitlab'").is_err());
+    }
+}
@@ -0,0 +1,68 @@
+use heck::{ToKebabCase, ToLowerCamelCase, ToSnakeCase, ToTitleCase, ToUpperCamelCase};
+
+/// The names of the filters, which can be applied to an answer.
+pub const FILTER_NAMES: [&str; 7] = ["snake", "kebab", "pascal", "camel", "upper", "lower", "title"];
+
+/// Checks that a filter with the given name exists.
+pub fn check_filter(filter: &str) -> Result<(), String> {
+    if FILTER_NAMES.contains(&filter) {
+        return Ok(());
+    }
+
+    Err(create_unknown_filter_error(filter))
+}
+
+/// Applies the filter with the given name to the value.
+///
+/// For example `my project` is converted by `snake` to `my_project` and by `pascal` to `MyProject`.
+pub fn apply_filter(filter: &str, value: &str) -> Result<String, String> {
+    let value = match filter {
+        "snake" => value.to_snake_case(),
+        "kebab" => value.to_kebab_case(),
+        "pascal" => value.to_upper_camel_case(),
+        "camel" => value.to_lower_camel_case(),
+        "upper" => value.to_uppercase(),
+        "lower" => value.to_lowercase(),
+        "title" => value.to_title_case(),
+        _ => return Err(create_unknown_filter_error(filter)),
+    };
+
+    Ok(value)
+}
+
+fn create_unknown_filter_error(filter: &str) -> String {
+    format!(
+        "Unknown filter {}, available filters are: {}",
+        filter,
+        FILTER_NAMES.join(", ")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply_filter_should_convert_case() {
+        let value = "My awesome-Project";
+
+        assert_eq!(apply_filter("snake", value), Ok("my_awesome_project".to_string()));
+        assert_eq!(apply_filter("kebab", value), Ok("my-awesome-project".to_string()));
+        assert_eq!(apply_filter("pascal", value), Ok("MyAwesomeProject".to_string()));
+        assert_eq!(apply_filter("camel", value), Ok("myAwesomeProject".to_string()));
+        assert_eq!(apply_filter("upper", value), Ok("MY AWESOME-PROJECT".to_string()));
+        assert_eq!(apply_filter("lower", value), Ok("my awesome-project".to_string()));
+        assert_eq!(apply_filter("title", value), Ok("My Awesome Project".to_string()));
+    }
+
+    #[test]
+    fn test_apply_filter_should_return_error_for_unknown_filter() {
+        assert_eq!(
+            apply_filter("reverse", "value"),
+            Err(
+                "Unknown filter reverse, available filters are: snake, kebab, pascal, camel, upper, lower, title"
+                    .into()
+            )
+        );
+    }
+}
@@ -0,0 +1,284 @@
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use common::core::errors::{Error, Result};
+use serde::Serialize;
+
+use super::template_engine::ConflictStrategy;
+
+/// The files, which a generation would write, without writing them.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct GenerationPlan {
+    /// The destination path of the generation.
+    #[serde(rename = "destination")]
+    pub destination_path: PathBuf,
+
+    /// True if the destination is deleted before the files are written (`--clean`).
+    pub clean: bool,
+
+    /// The reason, why the generation would be refused, for example a destination with files.
+    #[serde(rename = "refused")]
+    pub refusal: Option<String>,
+
+    /// The planned files in the order of the templates.
+    pub files: Vec<PlannedFile>,
+}
+
+/// A file of the generation plan.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PlannedFile {
+    /// The path of the file relative to the template input, files written by creatorly have no source.
+    #[serde(rename = "source")]
+    pub source_path: Option<PathBuf>,
+
+    /// The rendered path of the file in the destination.
+    #[serde(rename = "target")]
+    pub target_path: PathBuf,
+
+    /// Defines how the content is written.
+    pub action: PlannedAction,
+
+    /// The strategy for the file, if it exists already in the destination.
+    pub conflict: Option<ConflictStrategy>,
+}
+
+/// Defines how the content of a planned file is written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PlannedAction {
+    /// The content is rendered with the answers.
+    Render,
+
+    /// The content is copied as it is (binary files and `copy_without_render`).
+    Copy,
+
+    /// The content is written by creatorly (the record of the generation).
+    Write,
+}
+
+/// The format, in which the generation plan is printed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlanFormat {
+    Tree,
+    Json,
+}
+
+impl PlannedAction {
+    fn get_name(&self) -> &'static str {
+        match self {
+            PlannedAction::Render => "render",
+            PlannedAction::Copy => "copy",
+            PlannedAction::Write => "write",
+        }
+    }
+}
+
+impl GenerationPlan {
+    /// Returns the plan in the given format.
+    pub fn format(&self, format: PlanFormat) -> Result<String> {
+        match format {
+            PlanFormat::Tree => Ok(self.to_tree()),
+            PlanFormat::Json => self.to_json(),
+        }
+    }
+
+    /// Returns the target files as a tree below the destination. Every file shows its source, action and the
+    /// strategy for an existing file. A refused generation is shown below the tree.
+    ///
+    /// ```text
+    /// out
+    /// ├── .creatorly-answers.yml (write)
+    /// ├── My Project
+    /// │   └── index.html <- tpl/CREATORLY.project_name/index.html (render)
+    /// └── logo.png <- tpl/logo.png (copy, exists: skip)
+    /// ```
+    pub fn to_tree(&self) -> String {
+        let mut root = TreeNode::default();
+        for file in &self.files {
+            let relative_path = file
+                .target_path
+                .strip_prefix(&self.destination_path)
+                .unwrap_or(&file.target_path);
+
+            let mut node = &mut root;
+            for component in relative_path.components() {
+                let name = component.as_os_str().to_string_lossy().to_string();
+                node = node.children.entry(name).or_default();
+            }
+
+            node.file = Some(file);
+        }
+
+        let mut output = self.destination_path.display().to_string();
+        if self.clean {
+            output.push_str(" (deleted before)");
+        }
+
+        root.write_children("", &mut output);
+        if let Some(refusal) = &self.refusal {
+            output.push_str(&format!("\n\nThe generation is refused: {}", refusal));
+        }
+
+        output
+    }
+
+    /// Returns the plan as a JSON object with the destination and the list of files.
+    /// It returns an error, if a path is not valid UTF-8.
+    pub fn to_json(&self) -> Result<String> {
+        serde_json::to_string_pretty(self).map_err(|e| Error::new(format!("issue to write the plan as JSON: {}", e)))
+    }
+}
+
+#[derive(Default)]
+struct TreeNode<'a> {
+    file: Option<&'a PlannedFile>,
+    children: BTreeMap<String, TreeNode<'a>>,
+}
+
+impl TreeNode<'_> {
+    fn write_children(&self, indent: &str, output: &mut String) {
+        let count = self.children.len();
+        for (index, (name, child)) in self.children.iter().enumerate() {
+            let is_last = index + 1 == count;
+            let (branch, child_indent) = match is_last {
+                true => ("└── ", "    "),
+                false => ("├── ", "│   "),
+            };
+
+            output.push_str(&format!("\n{}{}{}", indent, branch, name));
+            if let Some(file) = child.file {
+                if let Some(source_path) = &file.source_path {
+                    output.push_str(&format!(" <- {}", source_path.display()));
+                }
+
+                let conflict = match file.conflict {
+                    Some(strategy) => format!(", exists: {}", get_conflict_name(strategy)),
+                    None => String::new(),
+                };
+                output.push_str(&format!(" ({}{})", file.action.get_name(), conflict));
+            }
+
+            child.write_children(&format!("{}{}", indent, child_indent), output);
+        }
+    }
+}
+
+fn get_conflict_name(strategy: ConflictStrategy) -> &'static str {
+    match strategy {
+        ConflictStrategy::Skip => "skip",
+        ConflictStrategy::Overwrite => "overwrite",
+        ConflictStrategy::Prompt => "prompt",
+        ConflictStrategy::Fail => "fail",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_plan() -> GenerationPlan {
+        GenerationPlan {
+            destination_path: PathBuf::from("out"),
+            clean: false,
+            refusal: None,
+            files: vec![
+                PlannedFile {
+                    source_path: Some(PathBuf::from("tpl/CREATORLY.project_name/index.html")),
+                    target_path: PathBuf::from("out/My Project/index.html"),
+                    action: PlannedAction::Render,
+                    conflict: None,
+                },
+                PlannedFile {
+                    source_path: Some(PathBuf::from("tpl/CREATORLY.project_name/style.css")),
+                    target_path: PathBuf::from("out/My Project/style.css"),
+                    action: PlannedAction::Render,
+                    conflict: None,
+                },
+                PlannedFile {
+                    source_path: Some(PathBuf::from("tpl/logo \"1\".png")),
+                    target_path: PathBuf::from("out/logo \"1\".png"),
+                    action: PlannedAction::Copy,
+                    conflict: Some(ConflictStrategy::Skip),
+                },
+                PlannedFile {
+                    source_path: None,
+                    target_path: PathBuf::from("out/.creatorly-answers.yml"),
+                    action: PlannedAction::Write,
+                    conflict: None,
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_to_tree_should_show_target_files_with_source() {
+        let plan = create_plan();
+
+        let tree = plan.to_tree();
+
+        assert_eq!(
+            tree,
+            "out\n\
+             ├── .creatorly-answers.yml (write)\n\
+             ├── My Project\n\
+             │   ├── index.html <- tpl/CREATORLY.project_name/index.html (render)\n\
+             │   └── style.css <- tpl/CREATORLY.project_name/style.css (render)\n\
+             └── logo \"1\".png <- tpl/logo \"1\".png (copy, exists: skip)"
+        );
+    }
+
+    #[test]
+    fn test_to_tree_should_show_clean_and_refusal() {
+        let mut plan = create_plan();
+        plan.clean = true;
+        plan.refusal = Some("The destination out is not empty".to_string());
+        plan.files.truncate(1);
+
+        let tree = plan.to_tree();
+
+        assert_eq!(
+            tree,
+            "out (deleted before)\n\
+             └── My Project\n    \
+             └── index.html <- tpl/CREATORLY.project_name/index.html (render)\n\
+             \n\
+             The generation is refused: The destination out is not empty"
+        );
+    }
+
+    #[test]
+    fn test_to_json_should_escape_paths() {
+        let plan = create_plan();
+
+        let json = plan.to_json().unwrap();
+
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["destination"], "out");
+        assert_eq!(value["clean"], false);
+        assert_eq!(value["refused"], serde_json::Value::Null);
+        assert_eq!(value["files"].as_array().unwrap().len(), 4);
+        assert_eq!(value["files"][0]["target"], "out/My Project/index.html");
+        assert_eq!(value["files"][0]["action"], "render");
+        assert_eq!(value["files"][2]["source"], "tpl/logo \"1\".png");
+        assert_eq!(value["files"][2]["action"], "copy");
+        assert_eq!(value["files"][2]["conflict"], "skip");
+        assert_eq!(value["files"][3]["source"], serde_json::Value::Null);
+        assert_eq!(value["files"][3]["action"], "write");
+        assert_eq!(value["files"][3]["conflict"], serde_json::Value::Null);
+    }
+
+    #[test]
+    fn test_to_json_should_write_empty_list() {
+        let plan = GenerationPlan {
+            destination_path: PathBuf::from("out"),
+            clean: false,
+            refusal: None,
+            files: vec![],
+        };
+
+        assert_eq!(
+            plan.to_json().unwrap(),
+            "{\n  \"destination\": \"out\",\n  \"clean\": false,\n  \"refused\": null,\n  \"files\": []\n}"
+        );
+    }
+}
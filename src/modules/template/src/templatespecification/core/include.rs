@@ -0,0 +1,510 @@
+use std::ops::Range;
+use std::path::{Component, Path, PathBuf};
+
+use common::core::errors::{Error, Result};
+use common::core::interfaces::FileSystemInterface;
+use futures::future::BoxFuture;
+use regex::Regex;
+
+use super::template_specification::{RenderEngine, TemplateSpecification};
+
+/// The directory in the template root for the shared files, which are included by other files.
+/// It is not part of the generated project.
+pub const PARTIALS_DIRECTORY: &str = "_partials";
+
+/// The content of a file, where the includes are replaced with the content of the included files.
+pub struct IncludedContent {
+    /// The content with the included files.
+    pub content: String,
+
+    /// The included files (also the nested ones) with their content as it is written in the file.
+    pub included_files: Vec<(PathBuf, String)>,
+}
+
+/// The matchers of the includes and of the raw tags for the placeholder id and delimiter of a template.
+/// They are compiled once per template and shared by all its files.
+pub struct IncludeMatchers {
+    /// The matchers of the includes and of the raw tags, none for the Jinja engine, whose syntax has no such includes.
+    regexes: Option<(Regex, Regex)>,
+}
+
+impl IncludeMatchers {
+    pub fn new(config: &TemplateSpecification) -> Result<Self> {
+        let regexes = match config.get_engine() {
+            RenderEngine::Regex => Some((create_include_regex(config)?, create_raw_regex(config)?)),
+            RenderEngine::Jinja => None,
+        };
+
+        Ok(Self { regexes })
+    }
+}
+
+/// Replaces the includes of the content, for example `CREATORLY.include(_partials/license_header.txt)`, with the
+/// content of the included files. The path is relative to the template root and the included files can include
+/// other files. It returns an error, if the files include each other in a cycle.
+///
+/// With `placeholder_escape`, a backslash before the include (`\CREATORLY.include(...)`) keeps it as it is, like the
+/// includes in raw blocks and raw files, whose text is not rendered. Absolute paths, `..` and symlinks,
+/// which lead outside of the template root, are refused, so that a template can not read other files of the host.
+/// The content of a template with the Jinja engine is kept as it is.
+pub async fn expand_includes(
+    content: &str,
+    file: &Path,
+    matchers: &IncludeMatchers,
+    template_root: &Path,
+    file_system: &dyn FileSystemInterface,
+) -> Result<IncludedContent> {
+    let Some((include_regex, raw_regex)) = &matchers.regexes else {
+        return Ok(IncludedContent {
+            content: content.to_string(),
+            included_files: vec![],
+        });
+    };
+
+    let mut include_stack = vec![file.to_path_buf()];
+    let mut included_files = vec![];
+
+    let content = expand(
+        content,
+        include_regex,
+        raw_regex,
+        template_root,
+        file_system,
+        &mut include_stack,
+        &mut included_files,
+    )
+    .await?;
+
+    Ok(IncludedContent {
+        content,
+        included_files,
+    })
+}
+
+/// Removes the includes of the content, so that only the own text of the file remains.
+/// The escaped includes and the includes in raw blocks and raw files are kept.
+pub fn remove_includes(content: &str, matchers: &IncludeMatchers) -> String {
+    let Some((include_regex, raw_regex)) = &matchers.regexes else {
+        return content.to_string();
+    };
+
+    let raw_ranges = get_raw_ranges(content, raw_regex);
+    let output = include_regex.replace_all(content, |include: &regex::Captures| {
+        let start = include.get(0).expect("the whole match exists").start();
+        if include.name("escape").is_some() || raw_ranges.iter().any(|range| range.contains(&start)) {
+            return include[0].to_string();
+        }
+
+        String::new()
+    });
+
+    output.to_string()
+}
+
+fn create_include_regex(config: &TemplateSpecification) -> Result<Regex> {
+    Regex::new(&format!(
+        r"{}include\((?P<path>[^()\n]+)\)",
+        create_prefix_pattern(config)
+    ))
+    .map_err(|e| Error::new(format!("Error creating regex: {}", e)))
+}
+
+/// Creates the matcher of the tags, which start and end a raw block or start a raw file.
+fn create_raw_regex(config: &TemplateSpecification) -> Result<Regex> {
+    Regex::new(&format!(
+        r"{}(?:(?P<raw>raw)|(?P<endraw>endraw)|(?P<rawfile>rawfile))\b",
+        create_prefix_pattern(config)
+    ))
+    .map_err(|e| Error::new(format!("Error creating regex: {}", e)))
+}
+
+/// Returns the pattern of the prefix (for example `CREATORLY.`) with the optional backslash, which escapes it, if
+/// escapes are enabled.
+fn create_prefix_pattern(config: &TemplateSpecification) -> String {
+    let prefix = regex::escape(&format!(
+        "{}{}",
+        config.get_placeholder_id(),
+        config.get_placeholder_delimiter()
+    ));
+
+    match config.get_placeholder_escape() {
+        true => format!(r"(?P<escape>\\)?{}", prefix),
+        false => prefix,
+    }
+}
+
+/// Returns the ranges of the raw blocks (from the start tag to the end tag) and the whole content of a raw file.
+fn get_raw_ranges(content: &str, raw_regex: &Regex) -> Vec<Range<usize>> {
+    let mut raw_ranges = vec![];
+    let mut raw_start = None;
+    let first_line_end = content.find('\n').unwrap_or(content.len());
+    for tag in raw_regex.captures_iter(content) {
+        let tag_range = tag.get(0).expect("the whole match exists").range();
+        if tag.name("escape").is_some() {
+            continue;
+        }
+
+        if tag.name("rawfile").is_some() && tag_range.start < first_line_end {
+            raw_ranges.clear();
+            raw_ranges.push(0..content.len());
+            return raw_ranges;
+        }
+
+        match raw_start {
+            None if tag.name("raw").is_some() => raw_start = Some(tag_range.start),
+            Some(start) if tag.name("endraw").is_some() => {
+                raw_ranges.push(start..tag_range.end);
+                raw_start = None;
+            }
+            _ => {}
+        }
+    }
+
+    // a raw block without an end is reported by the renderer, until then its text is not rendered
+    if let Some(start) = raw_start {
+        raw_ranges.push(start..content.len());
+    }
+
+    raw_ranges
+}
+
+fn expand<'a>(
+    content: &'a str,
+    include_regex: &'a Regex,
+    raw_regex: &'a Regex,
+    template_root: &'a Path,
+    file_system: &'a dyn FileSystemInterface,
+    include_stack: &'a mut Vec<PathBuf>,
+    included_files: &'a mut Vec<(PathBuf, String)>,
+) -> BoxFuture<'a, Result<String>> {
+    Box::pin(async move {
+        let includes: Vec<(std::ops::Range<usize>, bool, String)> = include_regex
+            .captures_iter(content)
+            .map(|include| {
+                let range = include.get(0).expect("the whole match exists").range();
+                (
+                    range,
+                    include.name("escape").is_some(),
+                    include["path"].trim().to_string(),
+                )
+            })
+            .collect();
+        let raw_ranges = get_raw_ranges(content, raw_regex);
+
+        let mut output = String::new();
+        let mut last_end = 0;
+        for (range, is_escaped, relative_path) in includes {
+            output.push_str(&content[last_end..range.start]);
+            last_end = range.end;
+
+            if is_escaped || raw_ranges.iter().any(|raw_range| raw_range.contains(&range.start)) {
+                output.push_str(&content[range]);
+                continue;
+            }
+
+            let path = template_root.join(&relative_path);
+            let resolved_path = resolve_include_path(&relative_path, template_root, file_system).await?;
+            if let Some(position) = include_stack.iter().position(|included_path| *included_path == path) {
+                let mut cycle: Vec<String> = include_stack[position..]
+                    .iter()
+                    .map(|included_path| get_display_path(included_path, template_root))
+                    .collect();
+                cycle.push(relative_path);
+
+                return Err(Error::new(format!(
+                    "The files include each other: {}",
+                    cycle.join(" -> ")
+                )));
+            }
+
+            let included_content = file_system
+                .read_file(&resolved_path)
+                .await
+                .map_err(|e| Error::new(format!("Can not include {}: {}", relative_path, e)))?;
+            included_files.push((path.clone(), included_content.clone()));
+
+            include_stack.push(path);
+            let included_content = expand(
+                &included_content,
+                include_regex,
+                raw_regex,
+                template_root,
+                file_system,
+                include_stack,
+                included_files,
+            )
+            .await?;
+            include_stack.pop();
+
+            // the include is usually written in its own line, so the line break of the file is already there
+            output.push_str(included_content.strip_suffix('\n').unwrap_or(&included_content));
+        }
+
+        output.push_str(&content[last_end..]);
+        Ok(output)
+    })
+}
+
+/// Returns the canonical path of the included file. It returns an error, if the path is absolute, contains `..` or
+/// leads outside of the template root (also with symlinks).
+async fn resolve_include_path(
+    relative_path: &str,
+    template_root: &Path,
+    file_system: &dyn FileSystemInterface,
+) -> Result<PathBuf> {
+    let is_inside = Path::new(relative_path)
+        .components()
+        .all(|component| matches!(component, Component::Normal(_) | Component::CurDir));
+    if !is_inside {
+        return Err(Error::with_advice(
+            format!("Can not include {}: the path is outside of the template", relative_path),
+            "Use a path relative to the template root without '..'".into(),
+        ));
+    }
+
+    let canonicalize = |path: PathBuf| async move {
+        file_system
+            .canonicalize(&path)
+            .await
+            .map_err(|e| Error::new(format!("Can not include {}: {}", relative_path, e)))
+    };
+    let canonical_root = canonicalize(template_root.to_path_buf()).await?;
+    let canonical_path = canonicalize(template_root.join(relative_path)).await?;
+    if !canonical_path.starts_with(&canonical_root) {
+        return Err(Error::with_advice(
+            format!("Can not include {}: the path is outside of the template", relative_path),
+            "Use a file inside of the template instead of a symlink to another location".into(),
+        ));
+    }
+
+    Ok(canonical_path)
+}
+
+fn get_display_path(path: &Path, template_root: &Path) -> String {
+    path.strip_prefix(template_root).unwrap_or(path).display().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::infrastructure::file_system::FileSystem;
+    use std::fs;
+    use tempdir::TempDir;
+
+    fn create_template_root(files: &[(&str, &str)]) -> TempDir {
+        let temp_dir = TempDir::new("test_includes").expect("Failed to create temp dir");
+        for (path, content) in files {
+            let path = temp_dir.path().join(path);
+            fs::create_dir_all(path.parent().unwrap()).expect("Failed to create dir");
+            fs::write(path, content).expect("Failed to write file");
+        }
+
+        temp_dir
+    }
+
+    /// creates the matchers of a specification, where a backslash before an include writes it literally
+    fn create_escape_matchers() -> IncludeMatchers {
+        let specification: TemplateSpecification =
+            serde_yaml::from_str("placeholder_escape: true\nplaceholders: {}\n").unwrap();
+        IncludeMatchers::new(&specification).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_expand_includes_should_replace_nested_includes() {
+        // arrange
+        let template_root = create_template_root(&[
+            (
+                "_partials/header.txt",
+                "// CREATORLY.project_name\nCREATORLY.include(_partials/license.txt)\n",
+            ),
+            ("_partials/license.txt", "// MIT\n"),
+        ]);
+        let content = "CREATORLY.include(_partials/header.txt)\nfn main() {}\n\\CREATORLY.include(literal.txt)\n";
+
+        // act
+        let included_content = expand_includes(
+            content,
+            &template_root.path().join("main.rs"),
+            &create_escape_matchers(),
+            template_root.path(),
+            &FileSystem {},
+        )
+        .await
+        .unwrap();
+
+        // assert
+        assert_eq!(
+            included_content.content,
+            "// CREATORLY.project_name\n// MIT\nfn main() {}\n\\CREATORLY.include(literal.txt)\n"
+        );
+        assert_eq!(included_content.included_files.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_expand_includes_should_return_error_for_cycle() {
+        // arrange
+        let template_root = create_template_root(&[
+            ("_partials/a.txt", "CREATORLY.include(_partials/b.txt)"),
+            ("_partials/b.txt", "CREATORLY.include(_partials/a.txt)"),
+        ]);
+
+        // act
+        let result = expand_includes(
+            "CREATORLY.include(_partials/a.txt)",
+            &template_root.path().join("main.rs"),
+            &IncludeMatchers::new(&TemplateSpecification::new()).unwrap(),
+            template_root.path(),
+            &FileSystem {},
+        )
+        .await;
+
+        // assert
+        assert_eq!(
+            result.err().unwrap().to_string(),
+            "The files include each other: _partials/a.txt -> _partials/b.txt -> _partials/a.txt"
+        );
+    }
+
+    #[test]
+    fn test_remove_includes_should_keep_escaped_includes() {
+        let content = "CREATORLY.include(_partials/header.txt)\ntext \\CREATORLY.include(literal.txt)";
+
+        let output = remove_includes(content, &create_escape_matchers());
+
+        assert_eq!(output, "\ntext \\CREATORLY.include(literal.txt)");
+    }
+
+    #[tokio::test]
+    async fn test_expand_includes_should_keep_includes_in_raw_blocks() {
+        // arrange
+        let template_root = create_template_root(&[("_partials/header.txt", "// header\n")]);
+        let content = "CREATORLY.raw\nCREATORLY.include(_partials/header.txt)\nCREATORLY.endraw\n\
+                       CREATORLY.include(_partials/header.txt)\n";
+
+        // act
+        let included_content = expand_includes(
+            content,
+            &template_root.path().join("main.rs"),
+            &IncludeMatchers::new(&TemplateSpecification::new()).unwrap(),
+            template_root.path(),
+            &FileSystem {},
+        )
+        .await
+        .unwrap();
+
+        // assert
+        assert_eq!(
+            included_content.content,
+            "CREATORLY.raw\nCREATORLY.include(_partials/header.txt)\nCREATORLY.endraw\n// header\n"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_expand_includes_should_keep_content_of_jinja_templates() {
+        // arrange
+        let template_root = create_template_root(&[("_partials/header.txt", "// header\n")]);
+        let jinja_specification: TemplateSpecification =
+            serde_yaml::from_str("engine: jinja\nplaceholders: {}\n").unwrap();
+        let matchers = IncludeMatchers::new(&jinja_specification).unwrap();
+        let content = "CREATORLY.include(_partials/header.txt)\n{{ project_name }}\n";
+
+        // act
+        let included_content = expand_includes(
+            content,
+            &template_root.path().join("main.rs"),
+            &matchers,
+            template_root.path(),
+            &FileSystem {},
+        )
+        .await
+        .unwrap();
+
+        // assert
+        assert_eq!(included_content.content, content);
+        assert!(included_content.included_files.is_empty());
+        assert_eq!(remove_includes(content, &matchers), content);
+    }
+
+    #[tokio::test]
+    async fn test_expand_includes_should_keep_includes_in_raw_files() {
+        // arrange
+        let template_root = create_template_root(&[("_partials/header.txt", "// header\n")]);
+        let content = "# CREATORLY.rawfile\nCREATORLY.include(_partials/header.txt)\n";
+
+        // act
+        let included_content = expand_includes(
+            content,
+            &template_root.path().join("main.rs"),
+            &IncludeMatchers::new(&TemplateSpecification::new()).unwrap(),
+            template_root.path(),
+            &FileSystem {},
+        )
+        .await
+        .unwrap();
+
+        // assert
+        assert_eq!(included_content.content, content);
+    }
+
+    async fn expand_single_include(template_root: &TempDir, relative_path: &str) -> Result<IncludedContent> {
+        expand_includes(
+            &format!("CREATORLY.include({})", relative_path),
+            &template_root.path().join("main.rs"),
+            &IncludeMatchers::new(&TemplateSpecification::new()).unwrap(),
+            template_root.path(),
+            &FileSystem {},
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn test_expand_includes_should_refuse_parent_directory() {
+        // arrange
+        let outside = create_template_root(&[("secret.txt", "secret")]);
+        let template_root = create_template_root(&[]);
+        let relative_path = format!(
+            "../{}/secret.txt",
+            outside.path().file_name().unwrap().to_string_lossy()
+        );
+
+        // act
+        let result = expand_single_include(&template_root, &relative_path).await;
+
+        // assert
+        assert!(result.err().unwrap().to_string().contains("outside of the template"));
+    }
+
+    #[tokio::test]
+    async fn test_expand_includes_should_refuse_absolute_path() {
+        // arrange
+        let outside = create_template_root(&[("secret.txt", "secret")]);
+        let template_root = create_template_root(&[]);
+        let absolute_path = outside.path().join("secret.txt");
+
+        // act
+        let result = expand_single_include(&template_root, absolute_path.to_str().unwrap()).await;
+
+        // assert
+        assert!(result.err().unwrap().to_string().contains("outside of the template"));
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_expand_includes_should_refuse_symlink_outside_of_template() {
+        // arrange
+        let outside = create_template_root(&[("secret.txt", "secret")]);
+        let template_root = create_template_root(&[]);
+        fs::create_dir_all(template_root.path().join("_partials")).unwrap();
+        std::os::unix::fs::symlink(
+            outside.path().join("secret.txt"),
+            template_root.path().join("_partials/link.txt"),
+        )
+        .unwrap();
+
+        // act
+        let result = expand_single_include(&template_root, "_partials/link.txt").await;
+
+        // assert
+        assert!(result.err().unwrap().to_string().contains("outside of the template"));
+    }
+}
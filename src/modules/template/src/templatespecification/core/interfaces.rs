@@ -1,3 +1,4 @@
+use crate::templatespecification::core::answer::Answer;
 use crate::templatespecification::core::template_specification::TemplateSpecification;
 use common::core::errors::Result;
 use std::{
@@ -42,6 +43,20 @@ pub trait FileListLoader {
     ///
     /// Returns a `Result` containing the loaded `FileList` if successful, or an error message as a `String` if unsuccessful.
     async fn load(&self, path: &Path) -> Result<Vec<PathBuf>>;
+
+    /// Returns the revision of the loaded files (for example the git commit), if the source is versioned.
+    async fn get_revision(&self, _path: &Path) -> Result<Option<String>> {
+        Ok(None)
+    }
+}
+
+/// A placeholder in the input, which has no answer.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UndefinedPlaceholder {
+    /// The line of the placeholder in the input, if the renderer knows it.
+    pub line_number: Option<usize>,
+    /// The placeholder as it is written in the input, for example `CREATORLY.projekt_name`.
+    pub placeholder: String,
 }
 
 #[cfg_attr(test, automock)]
@@ -49,5 +64,35 @@ pub trait FileListLoader {
 /// The input can be the content of a file or a path to a file
 pub trait TemplateRenderer: Send + Sync {
     /// render the input with the given template specification and answers
-    fn render(&self, input: &str, config: &TemplateSpecification, answers: &HashMap<String, String>) -> Result<String>;
+    fn render(&self, input: &str, config: &TemplateSpecification, answers: &HashMap<String, Answer>) -> Result<String>;
+
+    /// Returns the placeholders of the input, which have no answer.
+    fn find_undefined_placeholders(
+        &self,
+        _input: &str,
+        _config: &TemplateSpecification,
+        _answers: &HashMap<String, Answer>,
+    ) -> Result<Vec<UndefinedPlaceholder>> {
+        Ok(vec![])
+    }
+}
+
+#[cfg_attr(test, automock)]
+/// Trait for providing answers to the placeholders without asking the user.
+pub trait AnswerProvider: Send + Sync {
+    /// Returns the name of the source, which is used in messages (for example the path of an answers file).
+    fn get_source_name(&self) -> String;
+
+    /// Returns the answer for the given placeholder, if the source provides one.
+    fn get_answer(&self, key: &str) -> Option<String>;
+
+    /// Returns all keys which are provided by the source.
+    /// These keys are checked against the declared placeholders of the loaded template specifications.
+    fn get_keys(&self) -> Vec<String>;
+
+    /// Returns true if the source is the record of an earlier generation. The template may have changed since then,
+    /// so the answers of removed placeholders are ignored with a warning and new placeholders are reported.
+    fn is_record(&self) -> bool {
+        false
+    }
 }
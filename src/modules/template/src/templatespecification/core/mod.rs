@@ -1,10 +1,18 @@
 use std::path::PathBuf;
 
+pub mod answer;
+pub mod computed_expression;
+pub mod condition;
+pub mod filters;
+pub mod generation_plan;
+pub mod include;
 pub mod interfaces;
 pub mod service;
+pub mod staging;
 pub mod template_configuration;
 pub mod template_engine;
 pub mod template_specification;
+pub mod user_configuration;
 
 mod validate_template;
 
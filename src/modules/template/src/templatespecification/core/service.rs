@@ -1,13 +1,32 @@
-use super::interfaces::{ConfigurationLoader, FileListLoader};
+use super::answer::Answer;
+use super::computed_expression::sort_by_dependencies;
+use super::include::PARTIALS_DIRECTORY;
+use super::interfaces::{AnswerProvider, ConfigurationLoader, FileListLoader};
 use super::sort_by_directory_structure;
 use super::template_configuration::{TemplateConfiguration, TemplateConfigurationItem};
-use super::template_specification::{TemplateSpecification, TemplateSpecificationItemType};
-use super::validate_template::validate_template_configuration;
+use super::template_specification::{
+    split_multi_choice_answer, PlaceholderType, TemplateSpecification, TemplateSpecificationItemType,
+};
+use super::validate_template::{get_template_configuration_warnings, validate_template_configuration};
 use common::core::errors::{Error, Result};
 use common::core::user_interaction_interface::UserInteraction;
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
+/// Options, which define how the answers of the placeholders are resolved.
+#[derive(Clone, Default)]
+pub struct AnswerOptions {
+    /// The sources of predefined answers, the first source which provides an answer wins.
+    pub answer_providers: Vec<Arc<dyn AnswerProvider>>,
+
+    /// If set, the placeholders without a predefined answer get their default value instead of being prompted.
+    pub no_input: bool,
+
+    /// The default answers of the user, they replace the defaults of the template.
+    pub default_answers: HashMap<String, String>,
+}
+
 /// This struct represents the service for template specifications.
 pub struct TemplateSpecificationService {
     folder_loader: Arc<dyn FileListLoader + Send + Sync>,
@@ -40,10 +59,20 @@ impl TemplateSpecificationService {
         }
 
         validate_template_configuration(&template_configuration)?;
+        for warning in get_template_configuration_warnings(&template_configuration) {
+            self.user_interaction_interface
+                .print_diagnostic(&format!("Warning: {}", warning))
+                .await;
+        }
 
         Ok(template_configuration)
     }
 
+    /// returns the revision of the loaded template (for example the git commit), if the source is versioned
+    pub async fn get_template_revision(&self, entry_point_path: &Path) -> Result<Option<String>> {
+        self.folder_loader.get_revision(entry_point_path).await
+    }
+
     pub async fn save_template_specification(
         &self,
         path: PathBuf,
@@ -120,6 +149,12 @@ impl TemplateSpecificationService {
                 )));
             };
 
+            // the shared files of the includes are not part of the generated project
+            let template_root = found_creatorly_file.parent().unwrap_or(Path::new(""));
+            if other_file.starts_with(template_root.join(PARTIALS_DIRECTORY)) {
+                continue;
+            }
+
             for result in &mut results {
                 if result.0 == found_creatorly_file {
                     result.1.push(other_file.clone());
@@ -131,37 +166,171 @@ impl TemplateSpecificationService {
     }
 
     /// get the answer of the questions
-    pub async fn get_answers(&self, template_configuration: &mut TemplateConfiguration) -> Result<()> {
+    ///
+    /// The answer providers are asked first (in the given order), only the placeholders which are not answered by
+    /// any provider are prompted. In the no-input mode the default values are taken instead of prompting.
+    pub async fn get_answers(
+        &self,
+        template_configuration: &mut TemplateConfiguration,
+        answer_options: &AnswerOptions,
+    ) -> Result<()> {
         template_configuration.answers.clear();
 
+        let answer_providers = &answer_options.answer_providers;
+        for warning in check_unknown_answer_keys(template_configuration, answer_providers)? {
+            self.user_interaction_interface
+                .print_diagnostic(&format!("Warning: {}", warning))
+                .await;
+        }
+
+        // the template may have got new placeholders since the recorded generation, they are reported
+        let record = answer_providers.iter().find(|provider| provider.is_record());
+
         for template_configuration_item in &template_configuration.templates {
             for (key, template_specification_item) in &template_configuration_item.template_specification.placeholders {
                 let is_key_present = template_configuration.answers.contains_key(key);
-                if is_key_present {
+                let is_computed = template_specification_item
+                    .get_computed()
+                    .map_err(|error| Error::new(format!("Placeholder {}: {}", key, error)))?
+                    .is_some();
+                if is_key_present || is_computed {
+                    continue;
+                }
+
+                // skipped placeholders are rendered as an empty string
+                if !is_condition_fulfilled(key, template_specification_item, &template_configuration.answers)? {
+                    let answer = template_specification_item.to_answer(String::new());
+                    template_configuration.answers.insert(key.clone(), answer);
+                    continue;
+                }
+
+                let provided_answer = answer_providers
+                    .iter()
+                    .find_map(|provider| provider.get_answer(key).map(|answer| (provider, answer)));
+                if let Some((answer_provider, answer)) = provided_answer {
+                    let answer =
+                        parse_provided_answer(key, &answer, template_specification_item, answer_provider.as_ref())?;
+                    let answer = template_specification_item.to_answer(answer);
+                    template_configuration.answers.insert(key.clone(), answer);
                     continue;
                 }
 
-                let answer = match &template_specification_item {
-                    TemplateSpecificationItemType::SingleChoice(choice) => {
-                        let prompt = format!("{}: ", key);
-                        let mut answer = self.user_interaction_interface.get_input(&prompt, choice).await?;
-                        if answer.is_empty() {
-                            answer = choice.clone();
-                        }
-
-                        answer
-                    }
-                    TemplateSpecificationItemType::MultipleChoice(choices) => {
-                        let prompt = format!("{}: ", key);
-                        self.user_interaction_interface.get_selection(&prompt, choices).await?
-                    }
+                let default_value =
+                    get_default_value(key, template_specification_item, &answer_options.default_answers);
+                if let Some(record) = record {
+                    let message = match answer_options.no_input {
+                        true => format!(
+                            "Warning: The placeholder {} has no answer in the {}, the default value '{}' is taken",
+                            key,
+                            record.get_source_name(),
+                            default_value
+                        ),
+                        false => format!(
+                            "The placeholder {} has no answer in the {}",
+                            key,
+                            record.get_source_name()
+                        ),
+                    };
+                    self.user_interaction_interface.print_diagnostic(&message).await;
+                }
+
+                let answer = if answer_options.no_input {
+                    template_specification_item
+                        .parse_answer(&default_value)
+                        .map_err(|error| {
+                            Error::with_advice(
+                                format!("Invalid default value '{}' for placeholder {}", default_value, key),
+                                error,
+                            )
+                        })?
+                } else {
+                    self.prompt_answer(key, template_specification_item, &default_value)
+                        .await?
                 };
 
-                template_configuration.answers.insert(key.clone(), answer.to_string());
+                let answer = template_specification_item.to_answer(answer);
+                template_configuration.answers.insert(key.clone(), answer);
             }
         }
 
-        Ok(())
+        compute_answers(template_configuration)
+    }
+
+    /// prompt the user for the answer of a placeholder, until the answer is valid
+    async fn prompt_answer(
+        &self,
+        key: &str,
+        template_specification_item: &TemplateSpecificationItemType,
+        default_value: &str,
+    ) -> Result<String> {
+        if let Some(help) = template_specification_item.get_help() {
+            self.user_interaction_interface.print_diagnostic(help).await;
+        }
+
+        loop {
+            let answer = self
+                .prompt_raw_answer(key, template_specification_item, default_value)
+                .await?;
+
+            match template_specification_item.parse_answer(&answer) {
+                Ok(answer) => return Ok(answer),
+                Err(error) => {
+                    let msg = format!("Invalid answer '{}': {}", answer, error);
+                    self.user_interaction_interface.print_error(&msg).await;
+                }
+            }
+        }
+    }
+
+    /// prompt the user once with the prompt, which fits the type of the placeholder
+    async fn prompt_raw_answer(
+        &self,
+        key: &str,
+        template_specification_item: &TemplateSpecificationItemType,
+        default_value: &str,
+    ) -> Result<String> {
+        let prompt = template_specification_item.get_prompt(key);
+        let choices = template_specification_item.get_choices();
+        let answer = match template_specification_item.get_type() {
+            PlaceholderType::String | PlaceholderType::Integer | PlaceholderType::List => {
+                let mut answer = self
+                    .user_interaction_interface
+                    .get_input(&prompt, default_value)
+                    .await?;
+                if answer.is_empty() {
+                    answer = default_value.to_string();
+                }
+
+                answer
+            }
+            PlaceholderType::Bool => {
+                let default = default_value == true.to_string();
+                let answer = self
+                    .user_interaction_interface
+                    .get_confirmation(&prompt, default)
+                    .await?;
+
+                answer.to_string()
+            }
+            PlaceholderType::Choice => {
+                let default_index = choices.iter().position(|choice| choice == default_value).unwrap_or(0);
+                self.user_interaction_interface
+                    .get_selection(&prompt, choices, default_index)
+                    .await?
+            }
+            PlaceholderType::MultiChoice => {
+                let default_entries = split_multi_choice_answer(default_value);
+                let defaults: Vec<bool> = choices.iter().map(|choice| default_entries.contains(choice)).collect();
+                let answer = self
+                    .user_interaction_interface
+                    .get_multi_selection(&prompt, choices, &defaults)
+                    .await?;
+
+                answer.join(", ")
+            }
+        };
+
+        Ok(answer)
     }
 
     /// get the default answer of the questions
@@ -183,17 +352,140 @@ impl TemplateSpecificationService {
     }
 }
 
+/// get the default value of a placeholder
+///
+/// A default answer of the user replaces the default of the template, if it is a valid answer for the placeholder.
+fn get_default_value(
+    key: &str,
+    template_specification_item: &TemplateSpecificationItemType,
+    default_answers: &HashMap<String, String>,
+) -> String {
+    default_answers
+        .get(key)
+        .and_then(|default_answer| template_specification_item.parse_answer(default_answer).ok())
+        .unwrap_or_else(|| template_specification_item.get_default())
+}
+
+/// computes the values of the computed placeholders, after their dependencies are answered
+fn compute_answers(template_configuration: &mut TemplateConfiguration) -> Result<()> {
+    let computed_placeholders = template_configuration.get_computed_placeholders().map_err(Error::new)?;
+    let sorted_keys = sort_by_dependencies(&computed_placeholders).map_err(Error::new)?;
+
+    for key in sorted_keys {
+        let template_specification_item = template_configuration
+            .get_placeholder(&key)
+            .ok_or_else(|| Error::new(format!("The computed placeholder {} is not declared", key)))?;
+
+        let value = if is_condition_fulfilled(&key, template_specification_item, &template_configuration.answers)? {
+            computed_placeholders[&key].evaluate(&template_configuration.answers)
+        } else {
+            String::new()
+        };
+
+        template_configuration.answers.insert(key, Answer::Single(value));
+    }
+
+    Ok(())
+}
+
+/// checks if the condition of a placeholder is fulfilled by the answers so far, it is true without a condition
+fn is_condition_fulfilled(
+    key: &str,
+    template_specification_item: &TemplateSpecificationItemType,
+    answers: &HashMap<String, Answer>,
+) -> Result<bool> {
+    let condition = template_specification_item
+        .get_condition()
+        .map_err(|error| Error::new(format!("Invalid condition of placeholder {}: {}", key, error)))?;
+
+    Ok(condition.is_none_or(|condition| condition.evaluate(answers)))
+}
+
+/// checks that a provided answer is allowed for the placeholder and returns it in its normalized form
+fn parse_provided_answer(
+    key: &str,
+    answer: &str,
+    template_specification_item: &TemplateSpecificationItemType,
+    answer_provider: &dyn AnswerProvider,
+) -> Result<String> {
+    template_specification_item.parse_answer(answer).map_err(|error| {
+        Error::with_advice(
+            format!(
+                "Invalid answer '{}' for placeholder {} in {}",
+                answer,
+                key,
+                answer_provider.get_source_name()
+            ),
+            error,
+        )
+    })
+}
+
+/// checks that the answer providers only provide answers for declared placeholders. the unknown keys of a record
+/// are returned as warnings, because the placeholders may have been renamed or removed since the generation
+fn check_unknown_answer_keys(
+    template_configuration: &TemplateConfiguration,
+    answer_providers: &[Arc<dyn AnswerProvider>],
+) -> Result<Vec<String>> {
+    let mut warnings = vec![];
+    let declared_keys: Vec<&String> = template_configuration
+        .templates
+        .iter()
+        .flat_map(|item| item.template_specification.placeholders.keys())
+        .collect();
+
+    for answer_provider in answer_providers {
+        let unknown_keys: Vec<String> = answer_provider
+            .get_keys()
+            .into_iter()
+            .filter(|key| !declared_keys.contains(&key))
+            .collect();
+
+        if unknown_keys.is_empty() {
+            continue;
+        }
+
+        if answer_provider.is_record() {
+            warnings.push(format!(
+                "The {} contain answers for placeholders, which the template does not declare anymore, they are \
+                 ignored: {}",
+                answer_provider.get_source_name(),
+                unknown_keys.join(", ")
+            ));
+            continue;
+        }
+
+        let declared_keys: Vec<&str> = declared_keys.iter().map(|key| key.as_str()).collect();
+        return Err(Error::with_advice(
+            format!(
+                "Unknown placeholders in {}: {}",
+                answer_provider.get_source_name(),
+                unknown_keys.join(", ")
+            ),
+            format!("The template declares the placeholders: {}", declared_keys.join(", ")),
+        ));
+    }
+
+    Ok(warnings)
+}
+
 #[cfg(test)]
 mod tests {
     use crate::templatespecification::{
-        core::{interfaces::MockConfigurationLoader, service::TemplateSpecificationService},
+        core::{
+            answer::Answer,
+            interfaces::{AnswerProvider, MockAnswerProvider, MockConfigurationLoader},
+            service::{AnswerOptions, TemplateSpecificationService},
+            template_configuration::{TemplateConfiguration, TemplateConfigurationItem},
+            template_specification::{TemplateSpecification, TemplateSpecificationItemType},
+        },
         infrastructure::folder_loader::local_file_loader::LocalFileListLoader,
     };
     use async_trait::async_trait;
     use common::core::errors::Result;
     use common::core::user_interaction_interface::UserInteraction;
     use mockall::mock;
-    use std::{fs, sync::Arc};
+    use std::{collections::HashMap, fs, path::PathBuf, sync::Arc};
     use tempdir::TempDir;
 
     mock! {
@@ -207,9 +499,15 @@ mod tests {
 
             async fn print(&self, message: &str);
 
+            async fn print_diagnostic(&self, message: &str);
+
             async fn get_input(&self, prompt: &str, default: &str) -> Result<String>;
 
-            async fn get_selection(&self, prompt: &str, choices: &[String]) -> Result<String>;
+            async fn get_selection(&self, prompt: &str, choices: &[String], default: usize) -> Result<String>;
+
+            async fn get_confirmation(&self, prompt: &str, default: bool) -> Result<bool>;
+
+            async fn get_multi_selection(&self, prompt: &str, choices: &[String], defaults: &[bool]) -> Result<Vec<String>>;
         }
     }
 
@@ -275,4 +573,629 @@ mod tests {
         assert_eq!(results[2].0, creatorly3);
         assert!(results[2].1.contains(&file6));
     }
+
+    #[tokio::test]
+    async fn test_load_files_should_exclude_partials() {
+        // arrange
+        let sut = TemplateSpecificationService::new(
+            Arc::new(LocalFileListLoader::new()),
+            Arc::new(MockConfigurationLoader::new()),
+            Arc::new(MockUserInteractionInterface::new()),
+        );
+
+        let temp_dir = TempDir::new("test_exclude_partials").expect("Failed to create temp dir");
+        let temp_path = temp_dir.path();
+        let partials_dir = temp_path.join("_partials");
+        let creatorly = temp_path.join("creatorly.yml");
+        let file = temp_path.join("file.txt");
+        let partial = partials_dir.join("license_header.txt");
+
+        fs::create_dir_all(&partials_dir).expect("Failed to create _partials dir");
+        std::fs::File::create(&creatorly).expect("Failed to create creatorly.yml");
+        std::fs::File::create(&file).expect("Failed to create file.txt");
+        std::fs::File::create(&partial).expect("Failed to create _partials/license_header.txt");
+
+        // act
+        let results = sut.load_files(temp_path).await.expect("Failed to load files");
+
+        // assert
+        assert_eq!(results, vec![(creatorly, vec![file])]);
+    }
+
+    fn create_template_configuration() -> TemplateConfiguration {
+        let mut specification = TemplateSpecification::new();
+        specification.placeholders.insert(
+            "project_name".to_string(),
+            TemplateSpecificationItemType::SingleChoice("Demo".to_string()),
+        );
+        specification.placeholders.insert(
+            "license".to_string(),
+            TemplateSpecificationItemType::MultipleChoice(vec!["MIT".to_string(), "BSD".to_string()]),
+        );
+
+        create_template_configuration_with_specification(specification)
+    }
+
+    fn create_template_configuration_with_specification(specification: TemplateSpecification) -> TemplateConfiguration {
+        let mut template_configuration = TemplateConfiguration::new();
+        template_configuration.templates.push(TemplateConfigurationItem::new(
+            PathBuf::from("creatorly.yml"),
+            specification,
+            vec![PathBuf::from("file.txt")],
+        ));
+
+        template_configuration
+    }
+
+    fn create_answer_provider(answers: &[(&str, &str)]) -> Arc<dyn AnswerProvider> {
+        create_answer_provider_with_source(answers, "answers file answers.yml", false)
+    }
+
+    fn create_record_answer_provider(answers: &[(&str, &str)]) -> Arc<dyn AnswerProvider> {
+        create_answer_provider_with_source(answers, "recorded answers .creatorly-answers.yml", true)
+    }
+
+    fn create_answer_provider_with_source(
+        answers: &[(&str, &str)],
+        source_name: &str,
+        is_record: bool,
+    ) -> Arc<dyn AnswerProvider> {
+        let source_name = source_name.to_string();
+        let answers: Vec<(String, String)> = answers
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect();
+        let keys: Vec<String> = answers.iter().map(|(key, _)| key.clone()).collect();
+
+        let mut answer_provider = MockAnswerProvider::new();
+        answer_provider
+            .expect_get_source_name()
+            .returning(move || source_name.clone());
+        answer_provider.expect_get_keys().returning(move || keys.clone());
+        answer_provider.expect_is_record().returning(move || is_record);
+        answer_provider.expect_get_answer().returning(move |key| {
+            answers
+                .iter()
+                .find(|(answer_key, _)| answer_key == key)
+                .map(|(_, value)| value.clone())
+        });
+
+        Arc::new(answer_provider)
+    }
+
+    #[tokio::test]
+    async fn test_get_answers_should_not_prompt_for_provided_answers() {
+        // arrange
+        let mut user_interaction = MockUserInteractionInterface::new();
+        user_interaction.expect_get_input().never();
+        user_interaction
+            .expect_get_selection()
+            .times(1)
+            .returning(|_, _, _| Ok("BSD".to_string()));
+
+        let sut = TemplateSpecificationService::new(
+            Arc::new(LocalFileListLoader::new()),
+            Arc::new(MockConfigurationLoader::new()),
+            Arc::new(user_interaction),
+        );
+        let mut template_configuration = create_template_configuration();
+        let answer_provider = create_answer_provider(&[("project_name", "MyProject")]);
+
+        let answer_options = AnswerOptions {
+            answer_providers: vec![answer_provider],
+            ..Default::default()
+        };
+
+        // act
+        sut.get_answers(&mut template_configuration, &answer_options)
+            .await
+            .unwrap();
+
+        // assert
+        assert_eq!(template_configuration.answers["project_name"].to_string(), "MyProject");
+        assert_eq!(template_configuration.answers["license"].to_string(), "BSD");
+    }
+
+    #[tokio::test]
+    async fn test_get_answers_should_return_error_for_unknown_keys() {
+        // arrange
+        let sut = TemplateSpecificationService::new(
+            Arc::new(LocalFileListLoader::new()),
+            Arc::new(MockConfigurationLoader::new()),
+            Arc::new(MockUserInteractionInterface::new()),
+        );
+        let mut template_configuration = create_template_configuration();
+        let answer_provider = create_answer_provider(&[("project_name", "MyProject"), ("projekt_name", "Typo")]);
+
+        let answer_options = AnswerOptions {
+            answer_providers: vec![answer_provider],
+            ..Default::default()
+        };
+
+        // act
+        let result = sut.get_answers(&mut template_configuration, &answer_options).await;
+
+        // assert
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Unknown placeholders in answers file answers.yml: projekt_name -> The template declares the placeholders: project_name, license"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_answers_should_warn_about_unknown_keys_of_record() {
+        // arrange
+        let mut user_interaction = MockUserInteractionInterface::new();
+        user_interaction
+            .expect_print_diagnostic()
+            .withf(|message| {
+                message
+                    == "Warning: The recorded answers .creatorly-answers.yml contain answers for placeholders, which \
+                        the template does not declare anymore, they are ignored: name"
+            })
+            .times(1)
+            .returning(|_| ());
+        let sut = TemplateSpecificationService::new(
+            Arc::new(LocalFileListLoader::new()),
+            Arc::new(MockConfigurationLoader::new()),
+            Arc::new(user_interaction),
+        );
+        let mut template_configuration = create_template_configuration();
+        let answer_provider =
+            create_record_answer_provider(&[("project_name", "MyProject"), ("license", "BSD"), ("name", "Renamed")]);
+
+        let answer_options = AnswerOptions {
+            answer_providers: vec![answer_provider],
+            no_input: true,
+            ..Default::default()
+        };
+
+        // act
+        sut.get_answers(&mut template_configuration, &answer_options)
+            .await
+            .unwrap();
+
+        // assert
+        assert_eq!(template_configuration.answers["project_name"].to_string(), "MyProject");
+        assert!(!template_configuration.answers.contains_key("name"));
+    }
+
+    #[tokio::test]
+    async fn test_get_answers_should_report_placeholders_without_recorded_answer() {
+        // arrange
+        let mut user_interaction = MockUserInteractionInterface::new();
+        user_interaction
+            .expect_print_diagnostic()
+            .withf(|message| {
+                message
+                    == "Warning: The placeholder license has no answer in the recorded answers \
+                        .creatorly-answers.yml, the default value 'MIT' is taken"
+            })
+            .times(1)
+            .returning(|_| ());
+        let sut = TemplateSpecificationService::new(
+            Arc::new(LocalFileListLoader::new()),
+            Arc::new(MockConfigurationLoader::new()),
+            Arc::new(user_interaction),
+        );
+        let mut template_configuration = create_template_configuration();
+        let answer_provider = create_record_answer_provider(&[("project_name", "MyProject")]);
+
+        let answer_options = AnswerOptions {
+            answer_providers: vec![answer_provider],
+            no_input: true,
+            ..Default::default()
+        };
+
+        // act
+        sut.get_answers(&mut template_configuration, &answer_options)
+            .await
+            .unwrap();
+
+        // assert
+        assert_eq!(template_configuration.answers["license"].to_string(), "MIT");
+    }
+
+    #[tokio::test]
+    async fn test_get_answers_should_prompt_placeholders_without_recorded_answer() {
+        // arrange
+        let mut user_interaction = MockUserInteractionInterface::new();
+        user_interaction
+            .expect_print_diagnostic()
+            .withf(|message| {
+                message == "The placeholder license has no answer in the recorded answers .creatorly-answers.yml"
+            })
+            .times(1)
+            .returning(|_| ());
+        user_interaction
+            .expect_get_selection()
+            .times(1)
+            .returning(|_, _, _| Ok("BSD".to_string()));
+        let sut = TemplateSpecificationService::new(
+            Arc::new(LocalFileListLoader::new()),
+            Arc::new(MockConfigurationLoader::new()),
+            Arc::new(user_interaction),
+        );
+        let mut template_configuration = create_template_configuration();
+        let answer_provider = create_record_answer_provider(&[("project_name", "MyProject")]);
+
+        let answer_options = AnswerOptions {
+            answer_providers: vec![answer_provider],
+            ..Default::default()
+        };
+
+        // act
+        sut.get_answers(&mut template_configuration, &answer_options)
+            .await
+            .unwrap();
+
+        // assert
+        assert_eq!(template_configuration.answers["license"].to_string(), "BSD");
+    }
+
+    #[tokio::test]
+    async fn test_get_answers_should_prefer_first_answer_provider() {
+        // arrange
+        let mut user_interaction = MockUserInteractionInterface::new();
+        user_interaction.expect_get_input().never();
+        user_interaction.expect_get_selection().never();
+
+        let sut = TemplateSpecificationService::new(
+            Arc::new(LocalFileListLoader::new()),
+            Arc::new(MockConfigurationLoader::new()),
+            Arc::new(user_interaction),
+        );
+        let mut template_configuration = create_template_configuration();
+        let set_answer_provider = create_answer_provider(&[("project_name", "Override")]);
+        let file_answer_provider = create_answer_provider(&[("project_name", "MyProject"), ("license", "MIT")]);
+
+        let answer_options = AnswerOptions {
+            answer_providers: vec![set_answer_provider, file_answer_provider],
+            ..Default::default()
+        };
+
+        // act
+        sut.get_answers(&mut template_configuration, &answer_options)
+            .await
+            .unwrap();
+
+        // assert
+        assert_eq!(template_configuration.answers["project_name"].to_string(), "Override");
+        assert_eq!(template_configuration.answers["license"].to_string(), "MIT");
+    }
+
+    #[tokio::test]
+    async fn test_get_answers_should_return_error_for_invalid_choice() {
+        // arrange
+        let sut = TemplateSpecificationService::new(
+            Arc::new(LocalFileListLoader::new()),
+            Arc::new(MockConfigurationLoader::new()),
+            Arc::new(MockUserInteractionInterface::new()),
+        );
+        let mut template_configuration = create_template_configuration();
+        let answer_provider = create_answer_provider(&[("project_name", "MyProject"), ("license", "GPL")]);
+
+        let answer_options = AnswerOptions {
+            answer_providers: vec![answer_provider],
+            ..Default::default()
+        };
+
+        // act
+        let result = sut.get_answers(&mut template_configuration, &answer_options).await;
+
+        // assert
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Invalid answer 'GPL' for placeholder license in answers file answers.yml -> Allowed choices are: MIT, BSD"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_answers_should_take_default_values_without_prompting_in_no_input_mode() {
+        // arrange
+        let mut user_interaction = MockUserInteractionInterface::new();
+        user_interaction.expect_get_input().never();
+        user_interaction.expect_get_selection().never();
+
+        let sut = TemplateSpecificationService::new(
+            Arc::new(LocalFileListLoader::new()),
+            Arc::new(MockConfigurationLoader::new()),
+            Arc::new(user_interaction),
+        );
+        let mut template_configuration = create_template_configuration();
+        let answer_options = AnswerOptions {
+            answer_providers: vec![],
+            no_input: true,
+            ..Default::default()
+        };
+
+        // act
+        sut.get_answers(&mut template_configuration, &answer_options)
+            .await
+            .unwrap();
+
+        // assert
+        assert_eq!(template_configuration.answers["project_name"].to_string(), "Demo");
+        assert_eq!(template_configuration.answers["license"].to_string(), "MIT");
+    }
+
+    #[tokio::test]
+    async fn test_get_answers_should_use_default_answers_of_the_user() {
+        // arrange
+        let mut user_interaction = MockUserInteractionInterface::new();
+        user_interaction
+            .expect_get_input()
+            .withf(|_, default| default == "UserDefault")
+            .times(1)
+            .returning(|_, _| Ok("".to_string()));
+        user_interaction
+            .expect_get_selection()
+            .withf(|_, _, default| *default == 1)
+            .times(1)
+            .returning(|_, choices, default| Ok(choices[default].clone()));
+
+        let sut = TemplateSpecificationService::new(
+            Arc::new(LocalFileListLoader::new()),
+            Arc::new(MockConfigurationLoader::new()),
+            Arc::new(user_interaction),
+        );
+        let mut template_configuration = create_template_configuration();
+        let answer_options = AnswerOptions {
+            default_answers: HashMap::from([
+                ("project_name".to_string(), "UserDefault".to_string()),
+                ("license".to_string(), "BSD".to_string()),
+            ]),
+            ..Default::default()
+        };
+
+        // act
+        sut.get_answers(&mut template_configuration, &answer_options)
+            .await
+            .unwrap();
+
+        // assert
+        assert_eq!(
+            template_configuration.answers["project_name"].to_string(),
+            "UserDefault"
+        );
+        assert_eq!(template_configuration.answers["license"].to_string(), "BSD");
+    }
+
+    #[tokio::test]
+    async fn test_get_answers_should_prompt_by_type() {
+        // arrange
+        let specification: TemplateSpecification = serde_yaml::from_str(
+            r#"
+placeholders:
+  use_docker:
+    type: bool
+    prompt: "Use docker?"
+    help: "Adds a Dockerfile"
+  port:
+    type: integer
+    default: 8080
+  features:
+    type: multi-choice
+    choices: [logging, metrics]
+"#,
+        )
+        .unwrap();
+        let mut template_configuration = create_template_configuration_with_specification(specification);
+
+        let mut user_interaction = MockUserInteractionInterface::new();
+        user_interaction
+            .expect_print_diagnostic()
+            .withf(|message| message == "Adds a Dockerfile")
+            .times(1)
+            .returning(|_| ());
+        user_interaction
+            .expect_get_confirmation()
+            .withf(|prompt, default| prompt == "Use docker?" && !default)
+            .times(1)
+            .returning(|_, _| Ok(true));
+        let mut inputs = vec![Ok("".to_string()), Ok("eighty".to_string())];
+        user_interaction
+            .expect_get_input()
+            .withf(|_, default| default == "8080")
+            .times(2)
+            .returning(move |_, _| inputs.pop().unwrap());
+        user_interaction.expect_print_error().times(1).returning(|_| ());
+        user_interaction
+            .expect_get_multi_selection()
+            .times(1)
+            .returning(|_, choices, _| Ok(choices.to_vec()));
+
+        let sut = TemplateSpecificationService::new(
+            Arc::new(LocalFileListLoader::new()),
+            Arc::new(MockConfigurationLoader::new()),
+            Arc::new(user_interaction),
+        );
+
+        // act
+        sut.get_answers(&mut template_configuration, &AnswerOptions::default())
+            .await
+            .unwrap();
+
+        // assert
+        assert_eq!(template_configuration.answers["use_docker"].to_string(), "true");
+        assert_eq!(template_configuration.answers["port"].to_string(), "8080");
+        assert_eq!(
+            template_configuration.answers["features"],
+            Answer::List(vec!["logging".to_string(), "metrics".to_string()])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_answers_should_prompt_until_answer_is_valid() {
+        // arrange
+        let specification: TemplateSpecification = serde_yaml::from_str(
+            r#"
+placeholders:
+  project_name:
+    default: "my_app"
+    validation:
+      pattern: "[a-z_]+"
+      message: "Use lowercase letters and underscores"
+"#,
+        )
+        .unwrap();
+        let mut template_configuration = create_template_configuration_with_specification(specification);
+
+        let mut user_interaction = MockUserInteractionInterface::new();
+        let mut inputs = vec![Ok("my_project".to_string()), Ok("my project".to_string())];
+        user_interaction
+            .expect_get_input()
+            .times(2)
+            .returning(move |_, _| inputs.pop().unwrap());
+        user_interaction
+            .expect_print_error()
+            .withf(|message| message == "Invalid answer 'my project': Use lowercase letters and underscores")
+            .times(1)
+            .returning(|_| ());
+
+        let sut = TemplateSpecificationService::new(
+            Arc::new(LocalFileListLoader::new()),
+            Arc::new(MockConfigurationLoader::new()),
+            Arc::new(user_interaction),
+        );
+
+        // act
+        sut.get_answers(&mut template_configuration, &AnswerOptions::default())
+            .await
+            .unwrap();
+
+        // assert
+        assert_eq!(template_configuration.answers["project_name"].to_string(), "my_project");
+    }
+
+    #[tokio::test]
+    async fn test_get_answers_should_reject_invalid_provided_answer() {
+        // arrange
+        let specification: TemplateSpecification = serde_yaml::from_str(
+            r#"
+placeholders:
+  project_name:
+    validation:
+      pattern: "[a-z_]+"
+"#,
+        )
+        .unwrap();
+        let mut template_configuration = create_template_configuration_with_specification(specification);
+
+        let sut = TemplateSpecificationService::new(
+            Arc::new(LocalFileListLoader::new()),
+            Arc::new(MockConfigurationLoader::new()),
+            Arc::new(MockUserInteractionInterface::new()),
+        );
+        let answer_options = AnswerOptions {
+            answer_providers: vec![create_answer_provider(&[("project_name", "my project")])],
+            ..Default::default()
+        };
+
+        // act
+        let result = sut.get_answers(&mut template_configuration, &answer_options).await;
+
+        // assert
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Invalid answer 'my project' for placeholder project_name in answers file answers.yml -> The answer has to match the pattern [a-z_]+"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_answers_should_skip_placeholders_with_unfulfilled_condition() {
+        // arrange
+        let specification: TemplateSpecification = serde_yaml::from_str(
+            r#"
+placeholders:
+  ci_provider: [github, gitlab]
+  gitlab_runner_tag:
+    when: "ci_provider == 'gitlab'"
+  github_runner:
+    when: "ci_provider == 'github'"
+    default: "ubuntu-latest"
+"#,
+        )
+        .unwrap();
+        let mut template_configuration = create_template_configuration_with_specification(specification);
+
+        let mut user_interaction = MockUserInteractionInterface::new();
+        user_interaction
+            .expect_get_selection()
+            .times(1)
+            .returning(|_, _, _| Ok("gitlab".to_string()));
+        user_interaction
+            .expect_get_input()
+            .withf(|prompt, _| prompt == "gitlab_runner_tag: ")
+            .times(1)
+            .returning(|_, _| Ok("docker".to_string()));
+
+        let sut = TemplateSpecificationService::new(
+            Arc::new(LocalFileListLoader::new()),
+            Arc::new(MockConfigurationLoader::new()),
+            Arc::new(user_interaction),
+        );
+        let answer_options = AnswerOptions {
+            answer_providers: vec![create_answer_provider(&[("github_runner", "macos-latest")])],
+            ..Default::default()
+        };
+
+        // act
+        sut.get_answers(&mut template_configuration, &answer_options)
+            .await
+            .unwrap();
+
+        // assert
+        assert_eq!(template_configuration.answers["ci_provider"].to_string(), "gitlab");
+        assert_eq!(
+            template_configuration.answers["gitlab_runner_tag"].to_string(),
+            "docker"
+        );
+        assert_eq!(template_configuration.answers["github_runner"].to_string(), "");
+    }
+
+    #[tokio::test]
+    async fn test_get_answers_should_compute_placeholders_in_dependency_order() {
+        // arrange
+        let specification: TemplateSpecification = serde_yaml::from_str(
+            r#"
+placeholders:
+  crate_name:
+    computed: "{{ project_slug | snake }}"
+  project_slug:
+    computed: "{{ project_name | kebab }}"
+  project_name: "Demo"
+"#,
+        )
+        .unwrap();
+        let mut template_configuration = create_template_configuration_with_specification(specification);
+
+        let mut user_interaction = MockUserInteractionInterface::new();
+        user_interaction
+            .expect_get_input()
+            .withf(|prompt, _| prompt == "project_name: ")
+            .times(1)
+            .returning(|_, _| Ok("My Awesome Project".to_string()));
+
+        let sut = TemplateSpecificationService::new(
+            Arc::new(LocalFileListLoader::new()),
+            Arc::new(MockConfigurationLoader::new()),
+            Arc::new(user_interaction),
+        );
+
+        // act
+        sut.get_answers(&mut template_configuration, &AnswerOptions::default())
+            .await
+            .unwrap();
+
+        // assert
+        assert_eq!(
+            template_configuration.answers["project_slug"].to_string(),
+            "my-awesome-project"
+        );
+        assert_eq!(
+            template_configuration.answers["crate_name"].to_string(),
+            "my_awesome_project"
+        );
+    }
 }
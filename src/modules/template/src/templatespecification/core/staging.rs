@@ -0,0 +1,337 @@
+use std::path::{Path, PathBuf};
+
+use common::core::errors::{Error, Result};
+use common::core::interfaces::FileSystemInterface;
+
+/// A staging directory next to the destination, for example `.my-project.creatorly-staging-1234` for `my-project`.
+///
+/// The files are rendered into the staging directory and moved into the destination only, if every file is
+/// rendered successfully. The replaced files of the destination are kept in the staging directory until all files
+/// are moved, so the destination is restored if moving a file fails.
+pub struct Staging {
+    destination_path: PathBuf,
+    absolute_destination_path: PathBuf,
+    staging_path: PathBuf,
+}
+
+impl Staging {
+    /// Creates the staging for the destination. Nothing is written until a file is rendered into it.
+    pub fn new(destination_path: &Path) -> Result<Self> {
+        let absolute_path = std::path::absolute(destination_path)
+            .map_err(|e| Error::new(format!("issue to resolve destination path: {}", e)))?;
+        let (Some(parent), Some(name)) = (absolute_path.parent(), absolute_path.file_name()) else {
+            return Err(Error::new(format!(
+                "The destination {} has no parent directory for the staging",
+                destination_path.display()
+            )));
+        };
+
+        let staging_name = format!(".{}.creatorly-staging-{}", name.to_string_lossy(), std::process::id());
+        Ok(Self {
+            destination_path: destination_path.to_path_buf(),
+            staging_path: parent.join(staging_name),
+            absolute_destination_path: absolute_path,
+        })
+    }
+
+    /// Returns the path in the staging directory for the target path in the destination.
+    pub fn get_staged_path(&self, target_path: &Path) -> PathBuf {
+        self.staging_path
+            .join("files")
+            .join(self.get_relative_path(target_path))
+    }
+
+    /// Moves the staged files into the destination and removes the staging directory.
+    /// With `clean` the previous content of the destination is replaced completely. The content is moved instead of
+    /// the destination itself, so that the destination can be the current directory.
+    /// If a file can not be moved, the destination is restored.
+    pub async fn commit(
+        &self,
+        target_paths: &[PathBuf],
+        clean: bool,
+        file_system: &dyn FileSystemInterface,
+    ) -> Result<()> {
+        let mut transaction = Transaction::default();
+        let result = self
+            .move_files(target_paths, clean, file_system, &mut transaction)
+            .await;
+        if let Err(error) = result {
+            self.rollback(transaction, file_system).await;
+            self.discard(file_system).await?;
+            return Err(error);
+        }
+
+        self.discard(file_system).await
+    }
+
+    /// Removes the staging directory with all staged files.
+    pub async fn discard(&self, file_system: &dyn FileSystemInterface) -> Result<()> {
+        file_system.clear_folder(&self.staging_path).await
+    }
+
+    async fn move_files(
+        &self,
+        target_paths: &[PathBuf],
+        clean: bool,
+        file_system: &dyn FileSystemInterface,
+        transaction: &mut Transaction,
+    ) -> Result<()> {
+        let backup_path = self.staging_path.join("backup");
+        transaction.is_destination_created = !file_system.exists(&self.absolute_destination_path).await?;
+
+        if clean && !transaction.is_destination_created {
+            transaction.is_destination_cleaned = true;
+            for entry_path in file_system.read_folder(&self.absolute_destination_path).await? {
+                let Some(name) = entry_path.file_name() else {
+                    continue;
+                };
+
+                let entry_backup_path = backup_path.join("destination").join(name);
+                file_system.rename(&entry_path, &entry_backup_path).await?;
+                transaction.cleaned_entries.push((entry_path, entry_backup_path));
+            }
+        }
+
+        for target_path in target_paths {
+            if file_system.exists(target_path).await? {
+                let file_backup_path = backup_path.join("files").join(self.get_relative_path(target_path));
+                file_system.rename(target_path, &file_backup_path).await?;
+                transaction.replaced_files.push((target_path.clone(), file_backup_path));
+            }
+
+            let staged_path = self.get_staged_path(target_path);
+            file_system.rename(&staged_path, target_path).await?;
+            transaction.moved_files.push((target_path.clone(), staged_path));
+        }
+
+        Ok(())
+    }
+
+    /// Moves the files back in the reverse order. The errors are ignored, so that as much as possible is restored.
+    async fn rollback(&self, transaction: Transaction, file_system: &dyn FileSystemInterface) {
+        for (target_path, staged_path) in transaction.moved_files.iter().rev() {
+            let _ = file_system.rename(target_path, staged_path).await;
+        }
+
+        for (target_path, file_backup_path) in transaction.replaced_files.iter().rev() {
+            let _ = file_system.rename(file_backup_path, target_path).await;
+        }
+
+        if transaction.is_destination_created {
+            let _ = file_system.clear_folder(&self.absolute_destination_path).await;
+        }
+
+        // the moved files are back in the staging directory, only the created folders are left in the destination
+        if transaction.is_destination_cleaned {
+            for entry_path in file_system
+                .read_folder(&self.absolute_destination_path)
+                .await
+                .unwrap_or_default()
+            {
+                let _ = file_system.clear_folder(&entry_path).await;
+            }
+        }
+
+        for (entry_path, entry_backup_path) in transaction.cleaned_entries.iter().rev() {
+            let _ = file_system.rename(entry_backup_path, entry_path).await;
+        }
+    }
+
+    fn get_relative_path<'a>(&self, target_path: &'a Path) -> &'a Path {
+        target_path.strip_prefix(&self.destination_path).unwrap_or(target_path)
+    }
+}
+
+/// The changes of the destination, which are undone by the rollback.
+#[derive(Default)]
+struct Transaction {
+    /// The target paths with the path in the staging directory, where they come from.
+    moved_files: Vec<(PathBuf, PathBuf)>,
+
+    /// The replaced target paths with the path of their backup.
+    replaced_files: Vec<(PathBuf, PathBuf)>,
+
+    /// The files and folders of the destination, which are moved into the backup with `clean`, with their backup path.
+    cleaned_entries: Vec<(PathBuf, PathBuf)>,
+
+    /// True if the previous content of the destination is moved into the backup with `clean`.
+    is_destination_cleaned: bool,
+
+    /// True if the destination did not exist before.
+    is_destination_created: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use common::infrastructure::file_system::FileSystem;
+    use mockall::mock;
+    use std::fs;
+    use tempdir::TempDir;
+
+    mock! {
+        FileSystemInterface {}
+
+        #[async_trait]
+        impl FileSystemInterface for FileSystemInterface {
+            async fn clear_folder(&self, path: &Path) -> Result<()>;
+
+            async fn move_file(&self, source_path: &Path, target_path: &Path) -> Result<()>;
+
+            async fn rename(&self, source_path: &Path, target_path: &Path) -> Result<()>;
+
+            async fn read_file(&self, path: &Path) -> Result<String>;
+
+            async fn write_file(&self, path: &Path, content: &str) -> Result<()>;
+
+            async fn read_file_buffered(&self, path: &Path) -> Result<Vec<String>>;
+
+            async fn is_binary(&self, path: &Path) -> Result<bool>;
+
+            async fn exists(&self, path: &Path) -> Result<bool>;
+
+            async fn is_empty_folder(&self, path: &Path) -> Result<bool>;
+
+            async fn read_folder(&self, path: &Path) -> Result<Vec<PathBuf>>;
+
+            async fn canonicalize(&self, path: &Path) -> Result<PathBuf>;
+        }
+    }
+
+    /// creates a destination with the files `a.txt` and `old.txt` and stages the files `a.txt` and `b.txt`
+    fn create_staging(temp_dir: &TempDir) -> (Staging, PathBuf, Vec<PathBuf>) {
+        let destination_path = temp_dir.path().join("project");
+        fs::create_dir_all(&destination_path).unwrap();
+        fs::write(destination_path.join("a.txt"), "old a").unwrap();
+        fs::write(destination_path.join("old.txt"), "old").unwrap();
+
+        let staging = Staging::new(&destination_path).unwrap();
+        let target_paths = vec![destination_path.join("a.txt"), destination_path.join("b.txt")];
+        for target_path in &target_paths {
+            let staged_path = staging.get_staged_path(target_path);
+            fs::create_dir_all(staged_path.parent().unwrap()).unwrap();
+            fs::write(staged_path, "new").unwrap();
+        }
+
+        (staging, destination_path, target_paths)
+    }
+
+    /// creates a file system, which works on the disk, but fails to move the given staged file
+    fn create_failing_file_system(failing_path: PathBuf) -> MockFileSystemInterface {
+        let mut file_system = MockFileSystemInterface::new();
+        file_system.expect_exists().returning(|path| Ok(path.exists()));
+        file_system.expect_read_folder().returning(|path| {
+            let entries = fs::read_dir(path).map_err(|e| Error::new(e.to_string()))?;
+            Ok(entries.map(|entry| entry.unwrap().path()).collect())
+        });
+        file_system.expect_rename().returning(move |source_path, target_path| {
+            if source_path == failing_path {
+                return Err(Error::new("disk full".into()));
+            }
+
+            fs::create_dir_all(target_path.parent().unwrap()).unwrap();
+            fs::rename(source_path, target_path).map_err(|e| Error::new(e.to_string()))
+        });
+        file_system.expect_clear_folder().returning(|path| {
+            let _ = fs::remove_dir_all(path);
+            Ok(())
+        });
+
+        file_system
+    }
+
+    #[tokio::test]
+    async fn test_commit_should_move_staged_files_into_destination() {
+        // arrange
+        let temp_dir = TempDir::new("test_commit_staging").unwrap();
+        let (staging, destination_path, target_paths) = create_staging(&temp_dir);
+
+        // act
+        staging.commit(&target_paths, false, &FileSystem {}).await.unwrap();
+
+        // assert
+        assert_eq!(fs::read_to_string(destination_path.join("a.txt")).unwrap(), "new");
+        assert_eq!(fs::read_to_string(destination_path.join("b.txt")).unwrap(), "new");
+        assert_eq!(fs::read_to_string(destination_path.join("old.txt")).unwrap(), "old");
+        assert!(!staging.staging_path.exists());
+    }
+
+    #[tokio::test]
+    async fn test_commit_should_replace_destination_with_clean() {
+        // arrange
+        let temp_dir = TempDir::new("test_commit_staging_clean").unwrap();
+        let (staging, destination_path, target_paths) = create_staging(&temp_dir);
+
+        // act
+        staging.commit(&target_paths, true, &FileSystem {}).await.unwrap();
+
+        // assert
+        assert_eq!(fs::read_to_string(destination_path.join("a.txt")).unwrap(), "new");
+        assert!(!destination_path.join("old.txt").exists());
+        assert!(!staging.staging_path.exists());
+    }
+
+    #[tokio::test]
+    async fn test_commit_should_clean_destination_given_as_current_directory() {
+        // arrange
+        let temp_dir = TempDir::new("test_commit_staging_clean_dot").unwrap();
+        let destination_path = temp_dir.path().join("project");
+        fs::create_dir_all(&destination_path).unwrap();
+        fs::write(destination_path.join("old.txt"), "old").unwrap();
+
+        // the destination `.` of a generation in the current directory, like `regenerate --clean` in the project
+        let current_directory_path = destination_path.join(".");
+        let staging = Staging::new(&current_directory_path).unwrap();
+        let target_paths = vec![current_directory_path.join("a.txt")];
+        let staged_path = staging.get_staged_path(&target_paths[0]);
+        fs::create_dir_all(staged_path.parent().unwrap()).unwrap();
+        fs::write(staged_path, "new").unwrap();
+
+        // act
+        staging.commit(&target_paths, true, &FileSystem {}).await.unwrap();
+
+        // assert
+        assert_eq!(fs::read_to_string(destination_path.join("a.txt")).unwrap(), "new");
+        assert!(!destination_path.join("old.txt").exists());
+        assert!(!staging.staging_path.exists());
+    }
+
+    #[tokio::test]
+    async fn test_commit_should_restore_cleaned_destination_if_moving_fails() {
+        // arrange
+        let temp_dir = TempDir::new("test_commit_staging_clean_rollback").unwrap();
+        let (staging, destination_path, target_paths) = create_staging(&temp_dir);
+        let staged_b_path = staging.get_staged_path(&target_paths[1]);
+        let file_system = create_failing_file_system(staged_b_path);
+
+        // act
+        let result = staging.commit(&target_paths, true, &file_system).await;
+
+        // assert
+        assert!(result.is_err());
+        assert_eq!(fs::read_to_string(destination_path.join("a.txt")).unwrap(), "old a");
+        assert_eq!(fs::read_to_string(destination_path.join("old.txt")).unwrap(), "old");
+        assert!(!destination_path.join("b.txt").exists());
+        assert!(!staging.staging_path.exists());
+    }
+
+    #[tokio::test]
+    async fn test_commit_should_restore_destination_if_moving_fails() {
+        // arrange
+        let temp_dir = TempDir::new("test_commit_staging_rollback").unwrap();
+        let (staging, destination_path, target_paths) = create_staging(&temp_dir);
+        let staged_b_path = staging.get_staged_path(&target_paths[1]);
+        let file_system = create_failing_file_system(staged_b_path);
+
+        // act
+        let result = staging.commit(&target_paths, false, &file_system).await;
+
+        // assert
+        assert!(result.is_err());
+        assert_eq!(fs::read_to_string(destination_path.join("a.txt")).unwrap(), "old a");
+        assert_eq!(fs::read_to_string(destination_path.join("old.txt")).unwrap(), "old");
+        assert!(!destination_path.join("b.txt").exists());
+        assert!(!staging.staging_path.exists());
+    }
+}
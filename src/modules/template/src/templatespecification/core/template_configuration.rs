@@ -1,11 +1,17 @@
-use super::template_specification::TemplateSpecification;
+use super::answer::Answer;
+use super::computed_expression::ComputedExpression;
+use super::template_specification::{TemplateSpecification, TemplateSpecificationItemType};
+use indexmap::IndexMap;
 // use common::core::file::File;
-use std::{collections::HashMap, path::PathBuf};
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct TemplateConfiguration {
     /// The answers to the questions.
-    pub answers: HashMap<String, String>,
+    pub answers: HashMap<String, Answer>,
 
     /// The list of templates. Each template has a root path, a template specification, and a list of files.
     pub templates: Vec<TemplateConfigurationItem>,
@@ -18,6 +24,31 @@ impl TemplateConfiguration {
             templates: Vec::new(),
         }
     }
+
+    /// Returns the placeholder with the given key. If it is declared in several templates, the first one is returned.
+    pub fn get_placeholder(&self, key: &str) -> Option<&TemplateSpecificationItemType> {
+        self.templates
+            .iter()
+            .find_map(|item| item.template_specification.placeholders.get(key))
+    }
+
+    /// Returns the parsed expressions of the computed placeholders.
+    pub fn get_computed_placeholders(&self) -> Result<IndexMap<String, ComputedExpression>, String> {
+        let mut computed_placeholders = IndexMap::new();
+
+        for item in &self.templates {
+            for (key, placeholder) in &item.template_specification.placeholders {
+                if let Some(expression) = placeholder
+                    .get_computed()
+                    .map_err(|error| format!("Placeholder {}: {}", key, error))?
+                {
+                    computed_placeholders.entry(key.clone()).or_insert(expression);
+                }
+            }
+        }
+
+        Ok(computed_placeholders)
+    }
 }
 
 impl Default for TemplateConfiguration {
@@ -45,4 +76,9 @@ impl TemplateConfigurationItem {
             file_list,
         }
     }
+
+    /// Returns the root directory of the template, it is the directory of the creatorly.yml.
+    pub fn get_template_root(&self) -> PathBuf {
+        self.root_path.parent().map(Path::to_path_buf).unwrap_or_default()
+    }
 }
@@ -1,15 +1,74 @@
-use crate::templatespecification::infrastructure::regex_templaterenderer::RegexTemplateRenderer;
+use crate::templatespecification::infrastructure::engine_templaterenderer::EngineTemplateRenderer;
 
-use super::interfaces::TemplateRenderer;
-use super::template_configuration::{TemplateConfiguration, TemplateConfigurationItem};
-use ::futures::future::join_all;
+use super::answer::Answer;
+use super::generation_plan::{GenerationPlan, PlannedAction, PlannedFile};
+use super::include::{expand_includes, remove_includes, IncludeMatchers};
+use super::interfaces::{TemplateRenderer, UndefinedPlaceholder};
+use super::staging::Staging;
+use super::template_configuration::TemplateConfiguration;
+use super::template_specification::TemplateSpecification;
 use common::core::errors::Error;
 use common::core::errors::Result;
 use common::core::interfaces::FileSystemInterface;
 use common::core::user_interaction_interface::UserInteraction;
+use globset::GlobSet;
+use serde::Serialize;
 use std::collections::HashMap;
 use std::path::Path;
 use std::{path::PathBuf, sync::Arc};
+use tokio::task::JoinSet;
+
+/// Options, which define how the files are rendered and written.
+#[derive(Clone, Default)]
+pub struct RenderOptions {
+    /// Fail instead of warn, if the files use placeholders without an answer or can not be rendered.
+    pub strict: bool,
+
+    /// Defines what happens with the files, which exist already in the destination.
+    /// Without a strategy, a destination with files is refused.
+    pub on_conflict: Option<ConflictStrategy>,
+
+    /// Delete the destination before the files are written.
+    pub clean: bool,
+
+    /// The number of files, which are rendered at the same time. Without it, the number of CPUs is taken.
+    pub jobs: Option<usize>,
+}
+
+impl RenderOptions {
+    /// Returns the strategy for existing files, without a strategy the generation fails.
+    pub fn get_on_conflict(&self) -> ConflictStrategy {
+        self.on_conflict.unwrap_or(ConflictStrategy::Fail)
+    }
+
+    /// Returns the number of files, which are rendered at the same time, at least one.
+    pub fn get_jobs(&self) -> usize {
+        let jobs = self.jobs.unwrap_or_else(|| {
+            std::thread::available_parallelism()
+                .map(|parallelism| parallelism.get())
+                .unwrap_or(1)
+        });
+
+        jobs.max(1)
+    }
+}
+
+/// Defines what happens with a file, which exists already in the destination.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ConflictStrategy {
+    /// Keep the existing file.
+    Skip,
+
+    /// Replace the existing file.
+    Overwrite,
+
+    /// Ask the user, if the existing file is replaced.
+    Prompt,
+
+    /// Fail before any file is written.
+    Fail,
+}
 
 /// Struct for the function of the template engine
 #[derive(Clone)]
@@ -17,14 +76,30 @@ pub struct RenderPushArgument {
     pub input_path: PathBuf,
     pub destination_path: PathBuf,
     pub template_configuration: TemplateConfiguration,
+    pub options: RenderOptions,
+
+    /// Files with their path relative to the destination and their content, which are written together with the
+    /// rendered files (for example the record of the generation).
+    pub additional_files: Vec<(PathBuf, String)>,
 }
 
+/// The file to render with the shared data of its template, so that the arguments stay small for many files
 struct RenderArgument {
     file: PathBuf,
-    template: TemplateConfigurationItem,
-    answers: HashMap<String, String>,
+    template: Arc<TemplateContext>,
+}
+
+/// The data of a template, which is shared by the render arguments of all its files
+struct TemplateContext {
+    specification: TemplateSpecification,
+    template_root: PathBuf,
+    copy_without_render: GlobSet,
+    keep_file_names: GlobSet,
+    include_matchers: IncludeMatchers,
+    answers: Arc<HashMap<String, Answer>>,
     input_path: PathBuf,
     destination_path: PathBuf,
+    strict: bool,
 }
 
 #[derive(Clone)]
@@ -52,80 +127,403 @@ impl TemplateEngine {
         file_system: Arc<dyn FileSystemInterface>,
         user_interaction_interface: Arc<dyn UserInteraction>,
     ) -> Self {
-        let template_renderer = Arc::new(RegexTemplateRenderer {});
+        let template_renderer = Arc::new(EngineTemplateRenderer::default());
         Self::new(template_renderer, file_system, user_interaction_interface)
     }
 
-    /// render files and push it directly to the destination path (async with multiple threads - one thread per file)
+    /// render files into a staging directory and move them into the destination path, when all files are rendered
+    /// (async with multiple threads - one thread per file)
+    ///
+    /// first the file names of all files are rendered and the conflicts with existing files are resolved,
+    /// so nothing is written, if the generation is refused. if files fail or the user presses Ctrl-C, the staging
+    /// directory is discarded and the destination stays as it was, the errors of all files are returned together
     pub async fn render_and_push(self: &Arc<Self>, args: RenderPushArgument) -> Result<()> {
         let args = Arc::new(args);
-        let mut handles = vec![];
-
-        // clear the destination folder
-        self.file_system.clear_folder(&args.destination_path).await?;
-
-        let templates = args.template_configuration.templates.clone();
-        for template in templates.into_iter() {
-            let cloned_template = template.clone();
-
-            for file in template.file_list.into_iter() {
-                let cloned_self = Arc::clone(self);
-                let args = RenderArgument {
-                    file: file.clone(),
-                    template: cloned_template.clone(),
-                    answers: args.template_configuration.answers.clone(),
-                    input_path: args.input_path.clone(),
-                    destination_path: args.destination_path.clone(),
-                };
-
-                // spawn a new thread for each file, there is no implement a mutex for the file system ;)
-                let handle = tokio::spawn(async move {
-                    let _ = cloned_self.process_file(args).await;
-                });
-
-                handles.push(handle);
+        self.check_destination(&args).await?;
+
+        let mut undefined_placeholders = vec![];
+        // the target path of every file in the order of the render arguments, none if the file is skipped
+        let mut target_paths_by_file = vec![];
+        let mut conflicting_files = vec![];
+        let mut errors = vec![];
+        for render_argument in self.create_render_arguments(&args)? {
+            let (target_file_name, file_name_placeholders) = match self.render_file_name(&render_argument).await {
+                Ok(rendered_file_name) => rendered_file_name,
+                Err(error) => {
+                    errors.push(format!(
+                        "{}: {}",
+                        get_input_relative_path(&render_argument).display(),
+                        error
+                    ));
+                    target_paths_by_file.push(None);
+                    continue;
+                }
+            };
+            undefined_placeholders.extend(file_name_placeholders);
+
+            // with the clean option the whole destination is replaced, so there are no conflicts
+            if !args.options.clean && self.file_system.exists(Path::new(&target_file_name)).await? {
+                conflicting_files.push(target_file_name.clone());
+                if !self.resolve_conflict(&target_file_name, &args.options).await? {
+                    target_paths_by_file.push(None);
+                    continue;
+                }
             }
+
+            target_paths_by_file.push(Some(PathBuf::from(target_file_name)));
+        }
+
+        if !errors.is_empty() {
+            return Err(create_files_error(errors));
+        }
+
+        if args.options.get_on_conflict() == ConflictStrategy::Fail && !conflicting_files.is_empty() {
+            return Err(create_conflicts_error(conflicting_files.len()));
+        }
+
+        let staging = Staging::new(&args.destination_path)?;
+        let mut target_paths: Vec<PathBuf> = target_paths_by_file.iter().flatten().cloned().collect();
+        let files_to_render = self
+            .create_render_arguments(&args)?
+            .zip(target_paths_by_file)
+            .filter_map(|(render_argument, target_path)| Some((target_path?, render_argument)));
+        let mut tasks = JoinSet::new();
+        let result = tokio::select! {
+            result = self.render_files(files_to_render, &staging, args.options.get_jobs(), &mut tasks) => result,
+            _ = wait_for_ctrl_c() => Err(Error::new("The generation was cancelled, the destination is unchanged".into())),
+        };
+
+        // wait for the aborted tasks, so that no file is written into the staging directory after it is discarded
+        tasks.abort_all();
+        while tasks.join_next().await.is_some() {}
+
+        let result = match result {
+            Ok(file_placeholders) => {
+                undefined_placeholders.extend(file_placeholders);
+                undefined_placeholders.sort();
+                undefined_placeholders.dedup();
+                self.report_undefined_placeholders(&undefined_placeholders, &args.options)
+                    .await
+            }
+            Err(error) => Err(error),
+        };
+
+        let result = match result {
+            Ok(()) => self.stage_additional_files(&args, &staging, &mut target_paths).await,
+            Err(error) => Err(error),
+        };
+
+        if let Err(error) = result {
+            staging.discard(self.file_system.as_ref()).await?;
+            return Err(error);
+        }
+
+        staging
+            .commit(&target_paths, args.options.clean, self.file_system.as_ref())
+            .await
+    }
+
+    /// write the additional files into the staging directory, so that they are moved into the destination together
+    /// with the rendered files
+    async fn stage_additional_files(
+        &self,
+        args: &RenderPushArgument,
+        staging: &Staging,
+        target_paths: &mut Vec<PathBuf>,
+    ) -> Result<()> {
+        for (relative_path, content) in &args.additional_files {
+            let target_path = args.destination_path.join(relative_path);
+            self.file_system
+                .write_file(&staging.get_staged_path(&target_path), content)
+                .await?;
+            target_paths.push(target_path);
+        }
+
+        Ok(())
+    }
+
+    /// render the contents into the staging directory with one task per file, at most `jobs` tasks run at the same
+    /// time, so that the open files and the memory stay bounded also for templates with many files. the next file is
+    /// taken from the iterator only when a task is finished.
+    /// returns the placeholders without an answer, the errors of all failed files are returned together
+    async fn render_files(
+        self: &Arc<Self>,
+        files_to_render: impl Iterator<Item = (PathBuf, RenderArgument)>,
+        staging: &Staging,
+        jobs: usize,
+        tasks: &mut JoinSet<Result<Vec<String>>>,
+    ) -> Result<Vec<String>> {
+        let mut undefined_placeholders = vec![];
+        let mut errors = vec![];
+        for (target_path, render_argument) in files_to_render {
+            if tasks.len() >= jobs {
+                join_next_render_task(tasks, &mut undefined_placeholders, &mut errors).await;
+            }
+
+            let cloned_self = Arc::clone(self);
+            let staged_path = staging.get_staged_path(&target_path);
+            tasks.spawn(async move {
+                cloned_self
+                    .render_file_content(&staged_path, &render_argument)
+                    .await
+                    .map_err(|e| {
+                        Error::new(format!(
+                            "{}: {}",
+                            get_input_relative_path(&render_argument).display(),
+                            e
+                        ))
+                    })
+            });
+        }
+
+        while join_next_render_task(tasks, &mut undefined_placeholders, &mut errors).await {}
+
+        if !errors.is_empty() {
+            return Err(create_files_error(errors));
+        }
+
+        Ok(undefined_placeholders)
+    }
+
+    /// compute the files, which would be written, without writing anything. the reason, why the generation would be
+    /// refused, is part of the plan, so that the files are shown anyway
+    pub async fn plan(&self, args: &RenderPushArgument) -> Result<GenerationPlan> {
+        let mut refusal = self.check_destination(args).await.err();
+        let mut files = vec![];
+        for render_argument in self.create_render_arguments(args)? {
+            let (target_file_name, _) = self.render_file_name(&render_argument).await?;
+            let is_copy = render_argument
+                .template
+                .copy_without_render
+                .is_match(get_template_relative_path(&render_argument))
+                || self.file_system.is_binary(&render_argument.file).await?;
+
+            // with the clean option the whole destination is replaced, so there are no conflicts
+            let is_conflict = !args.options.clean && self.file_system.exists(Path::new(&target_file_name)).await?;
+            files.push(PlannedFile {
+                source_path: Some(get_input_relative_path(&render_argument).to_path_buf()),
+                conflict: is_conflict.then(|| args.options.get_on_conflict()),
+                target_path: PathBuf::from(target_file_name),
+                action: if is_copy {
+                    PlannedAction::Copy
+                } else {
+                    PlannedAction::Render
+                },
+            });
+        }
+
+        let conflicts = files
+            .iter()
+            .filter(|file| file.conflict == Some(ConflictStrategy::Fail))
+            .count();
+        if refusal.is_none() && conflicts > 0 {
+            refusal = Some(create_conflicts_error(conflicts));
+        }
+
+        // the additional files are always written, also over existing files
+        for (relative_path, _) in &args.additional_files {
+            let target_path = args.destination_path.join(relative_path);
+            let is_conflict = !args.options.clean && self.file_system.exists(&target_path).await?;
+            files.push(PlannedFile {
+                source_path: None,
+                target_path,
+                action: PlannedAction::Write,
+                conflict: is_conflict.then_some(ConflictStrategy::Overwrite),
+            });
+        }
+
+        Ok(GenerationPlan {
+            destination_path: args.destination_path.clone(),
+            clean: args.options.clean,
+            refusal: refusal.map(|error| error.to_string()),
+            files,
+        })
+    }
+
+    /// create the render arguments for all files of the templates, they are created one after another while
+    /// iterating and share the data of their template
+    fn create_render_arguments<'a>(
+        &self,
+        args: &'a RenderPushArgument,
+    ) -> Result<impl Iterator<Item = RenderArgument> + 'a> {
+        let answers = Arc::new(args.template_configuration.answers.clone());
+        let mut templates = vec![];
+        for template in &args.template_configuration.templates {
+            let specification = &template.template_specification;
+            let context = Arc::new(TemplateContext {
+                specification: specification.clone(),
+                template_root: template.get_template_root(),
+                copy_without_render: specification.get_copy_without_render().map_err(Error::new)?,
+                keep_file_names: specification.get_keep_file_names().map_err(Error::new)?,
+                include_matchers: IncludeMatchers::new(specification)?,
+                answers: answers.clone(),
+                input_path: args.input_path.clone(),
+                destination_path: args.destination_path.clone(),
+                strict: args.options.strict,
+            });
+            templates.push((&template.file_list, context));
         }
 
-        join_all(handles).await;
+        Ok(templates.into_iter().flat_map(|(file_list, context)| {
+            file_list.iter().map(move |file| RenderArgument {
+                file: file.clone(),
+                template: context.clone(),
+            })
+        }))
+    }
+
+    /// refuse a destination with files, if no conflict strategy is given and the destination is not replaced with
+    /// the clean option
+    async fn check_destination(&self, args: &RenderPushArgument) -> Result<()> {
+        if args.options.clean {
+            return Ok(());
+        }
+
+        if args.options.on_conflict.is_none() && !self.file_system.is_empty_folder(&args.destination_path).await? {
+            return Err(Error::with_advice(
+                format!("The destination {} is not empty", args.destination_path.display()),
+                "Choose an empty destination, use --on-conflict skip|overwrite|prompt|fail to merge the files or \
+                 --clean to delete the destination before"
+                    .into(),
+            ));
+        }
 
         Ok(())
     }
 
-    /// process one file
-    /// first it will render the filename and then the content of the file line by line
-    async fn process_file(&self, args: RenderArgument) -> Result<()> {
-        let target_file_name = self.render_file_name(&args).await?;
+    /// report the existing file and return true, if it should be overwritten
+    async fn resolve_conflict(&self, target_file_name: &str, options: &RenderOptions) -> Result<bool> {
+        let overwrite = match options.get_on_conflict() {
+            ConflictStrategy::Skip => false,
+            ConflictStrategy::Overwrite => true,
+            ConflictStrategy::Prompt => {
+                self.user_interface
+                    .get_confirmation(&format!("The file {} exists, overwrite it?", target_file_name), false)
+                    .await?
+            }
+            ConflictStrategy::Fail => {
+                self.user_interface
+                    .print_error(&format!("The file {} exists already", target_file_name))
+                    .await;
+                return Ok(false);
+            }
+        };
 
-        self.render_file_content(&target_file_name, &args).await?;
+        let message = match overwrite {
+            true => format!("Overwrite existing file {}", target_file_name),
+            false => format!("Skip existing file {}", target_file_name),
+        };
+        self.user_interface.print(&message).await;
+
+        Ok(overwrite)
+    }
+
+    /// print the undefined placeholders as warnings or return them as error in strict mode
+    async fn report_undefined_placeholders(
+        &self,
+        undefined_placeholders: &[String],
+        options: &RenderOptions,
+    ) -> Result<()> {
+        if undefined_placeholders.is_empty() {
+            return Ok(());
+        }
+
+        if options.strict {
+            return Err(Error::with_advice(
+                format!(
+                    "The template uses placeholders without an answer:\n  {}",
+                    undefined_placeholders.join("\n  ")
+                ),
+                "Declare the placeholders in the creatorly.yml or fix their names".into(),
+            ));
+        }
+
+        for undefined_placeholder in undefined_placeholders {
+            self.user_interface
+                .print_diagnostic(&format!(
+                    "Warning: placeholder without an answer in {}",
+                    undefined_placeholder
+                ))
+                .await;
+        }
 
         Ok(())
     }
 
+    /// returns the undefined placeholders of the input as `path:line: placeholder` or `path (file name): placeholder`
+    fn find_undefined_placeholders(
+        &self,
+        input: &str,
+        file: &Path,
+        args: &RenderArgument,
+        is_file_name: bool,
+    ) -> Result<Vec<String>> {
+        let relative_path = file.strip_prefix(&args.template.input_path).unwrap_or(file);
+        let undefined_placeholders = self.template_renderer.find_undefined_placeholders(
+            input,
+            &args.template.specification,
+            &args.template.answers,
+        )?;
+
+        let format_location = |undefined_placeholder: &UndefinedPlaceholder| match undefined_placeholder.line_number {
+            _ if is_file_name => format!("{} (file name)", relative_path.display()),
+            Some(line_number) => format!("{}:{}", relative_path.display(), line_number),
+            None => relative_path.display().to_string(),
+        };
+
+        Ok(undefined_placeholders
+            .iter()
+            .map(|undefined_placeholder| {
+                format!(
+                    "{}: {}",
+                    format_location(undefined_placeholder),
+                    undefined_placeholder.placeholder
+                )
+            })
+            .collect())
+    }
+
     /// render the file name if it contains template token
-    async fn render_file_name(&self, arg: &RenderArgument) -> Result<String> {
-        let Some(input_root_path) = arg.input_path.as_path().to_str() else {
+    /// returns the rendered file name and the placeholders of the file name, which have no answer
+    async fn render_file_name(&self, arg: &RenderArgument) -> Result<(String, Vec<String>)> {
+        let Some(input_root_path) = arg.template.input_path.as_path().to_str() else {
             return Err(Error::new(format!(
                 "Input path don't exist {}",
-                arg.input_path.display()
+                arg.template.input_path.display()
             )));
         };
 
-        let Some(destination_path) = arg.destination_path.as_path().to_str() else {
+        let Some(destination_path) = arg.template.destination_path.as_path().to_str() else {
             return Err(Error::new(format!(
                 "Destination path don't exist {}",
                 arg.file.display()
             )));
         };
 
+        // the names of these files are kept as they are
+        if arg.template.keep_file_names.is_match(get_template_relative_path(arg)) {
+            let file_name = arg.file.to_str().unwrap().replace(input_root_path, destination_path);
+            return Ok((file_name, vec![]));
+        }
+
         let renderd_file_name_result = self.template_renderer.render(
             arg.file.to_str().unwrap(),
-            &arg.template.template_specification,
-            &arg.answers,
+            &arg.template.specification,
+            &arg.template.answers,
         );
 
+        let mut undefined_placeholders = vec![];
         let rendered_file_name: PathBuf = match renderd_file_name_result {
-            Ok(renderd_file_name) => PathBuf::from(renderd_file_name),
+            Ok(renderd_file_name) => {
+                let relative_file_name = arg.file.strip_prefix(&arg.template.input_path).unwrap_or(&arg.file);
+                undefined_placeholders =
+                    self.find_undefined_placeholders(relative_file_name.to_str().unwrap(), &arg.file, arg, true)?;
+
+                PathBuf::from(renderd_file_name)
+            }
+            Err(error) if arg.template.strict => {
+                return Err(Error::new(format!("While rendering the file name: {}", error)))
+            }
             Err(error) => {
                 self.user_interface
                     .print_error(format!("While rendering path {}: {}", arg.file.display(), error).as_str())
@@ -137,27 +535,67 @@ impl TemplateEngine {
 
         let rendered_file_name_str = rendered_file_name.to_str().unwrap();
         let rendered_file_name = rendered_file_name_str.replace(input_root_path, destination_path);
-        Ok(rendered_file_name)
+        Ok((rendered_file_name, undefined_placeholders))
     }
 
-    /// render the file content line by line
-    async fn render_file_content(&self, target_file_path: impl AsRef<Path>, args: &RenderArgument) -> Result<()> {
-        // if the file is a binary, move it directly
-        if self.file_system.is_binary(&args.file).await? {
+    /// render the file content line by line, the includes are replaced with the content of the included files before
+    /// returns the placeholders of the content and the included files, which have no answer
+    async fn render_file_content(
+        &self,
+        target_file_path: impl AsRef<Path>,
+        args: &RenderArgument,
+    ) -> Result<Vec<String>> {
+        // if the file is copied without rendering or is a binary, move it directly
+        if args
+            .template
+            .copy_without_render
+            .is_match(get_template_relative_path(args))
+            || self.file_system.is_binary(&args.file).await?
+        {
             self.file_system
                 .move_file(&args.file, target_file_path.as_ref())
                 .await?;
-            return Ok(());
+            return Ok(vec![]);
         }
 
         let content = self.file_system.read_file(&args.file).await?;
 
-        let output =
-            self.template_renderer
-                .render(content.as_str(), &args.template.template_specification, &args.answers);
+        let specification = &args.template.specification;
+        let output = match expand_includes(
+            &content,
+            &args.file,
+            &args.template.include_matchers,
+            &args.template.template_root,
+            self.file_system.as_ref(),
+        )
+        .await
+        {
+            Ok(included_content) => self
+                .template_renderer
+                .render(&included_content.content, specification, &args.template.answers)
+                .map(|rendered_content| (rendered_content, included_content.included_files)),
+            Err(error) => Err(error),
+        };
 
+        let mut undefined_placeholders = vec![];
         let rendered_content = match output {
-            Ok(rendered_content) => rendered_content,
+            Ok((rendered_content, included_files)) => {
+                let own_content = remove_includes(&content, &args.template.include_matchers);
+                undefined_placeholders = self.find_undefined_placeholders(&own_content, &args.file, args, false)?;
+
+                for (included_file, included_content) in included_files {
+                    let own_content = remove_includes(&included_content, &args.template.include_matchers);
+                    undefined_placeholders.extend(self.find_undefined_placeholders(
+                        &own_content,
+                        &included_file,
+                        args,
+                        false,
+                    )?);
+                }
+
+                rendered_content
+            }
+            Err(error) if args.template.strict => return Err(error),
             Err(error) => {
                 self.user_interface
                     .print_error(format!("While rendering content of path {}: {}", args.file.display(), error).as_str())
@@ -170,6 +608,428 @@ impl TemplateEngine {
             .write_file(target_file_path.as_ref(), rendered_content.as_str())
             .await?;
 
-        Ok(())
+        Ok(undefined_placeholders)
+    }
+}
+
+/// waits for the next render task and collects its placeholders without an answer or its error
+/// returns false, if there is no task left
+async fn join_next_render_task(
+    tasks: &mut JoinSet<Result<Vec<String>>>,
+    undefined_placeholders: &mut Vec<String>,
+    errors: &mut Vec<String>,
+) -> bool {
+    let Some(result) = tasks.join_next().await else {
+        return false;
+    };
+
+    match result {
+        Ok(Ok(file_placeholders)) => undefined_placeholders.extend(file_placeholders),
+        Ok(Err(error)) => errors.push(error.to_string()),
+        Err(error) => errors.push(format!("The render task failed: {}", error)),
+    }
+
+    true
+}
+
+/// the error for existing files in the destination, if the generation should fail for them
+fn create_conflicts_error(count: usize) -> Error {
+    Error::with_advice(
+        format!("{} files exist already in the destination", count),
+        "Choose another destination or use --on-conflict skip|overwrite|prompt".into(),
+    )
+}
+
+/// combines the errors of the files into one error, the errors start with the path of the file
+fn create_files_error(mut errors: Vec<String>) -> Error {
+    errors.sort();
+    Error::new(format!(
+        "{} files could not be generated, the destination is unchanged:\n  {}",
+        errors.len(),
+        errors.join("\n  ")
+    ))
+}
+
+/// waits until the user presses Ctrl-C, it never returns if the signal can not be received
+async fn wait_for_ctrl_c() {
+    if tokio::signal::ctrl_c().await.is_err() {
+        std::future::pending::<()>().await;
+    }
+}
+
+/// returns the path of the file relative to the input path, the errors and warnings show it
+fn get_input_relative_path(args: &RenderArgument) -> &Path {
+    args.file.strip_prefix(&args.template.input_path).unwrap_or(&args.file)
+}
+
+/// returns the path of the file relative to the root of its template, the glob patterns are matched against it
+fn get_template_relative_path(args: &RenderArgument) -> PathBuf {
+    args.file
+        .strip_prefix(&args.template.template_root)
+        .unwrap_or(&args.file)
+        .to_path_buf()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::templatespecification::core::template_configuration::TemplateConfigurationItem;
+    use async_trait::async_trait;
+    use common::infrastructure::file_system::FileSystem;
+    use mockall::mock;
+    use std::fs;
+    use tempdir::TempDir;
+
+    mock! {
+        UserInteractionInterface {}
+
+        #[async_trait]
+        impl UserInteraction for UserInteractionInterface {
+            async fn print_success(&self, message: &str);
+
+            async fn print_error(&self, message: &str);
+
+            async fn print(&self, message: &str);
+
+            async fn print_diagnostic(&self, message: &str);
+
+            async fn get_input(&self, prompt: &str, default: &str) -> Result<String>;
+
+            async fn get_selection(&self, prompt: &str, choices: &[String], default: usize) -> Result<String>;
+
+            async fn get_confirmation(&self, prompt: &str, default: bool) -> Result<bool>;
+
+            async fn get_multi_selection(&self, prompt: &str, choices: &[String], defaults: &[bool]) -> Result<Vec<String>>;
+        }
+    }
+
+    /// creates a template with the files `a.txt` and `b.txt` and a destination with an existing `b.txt`
+    fn create_render_push_argument(temp_dir: &TempDir, options: RenderOptions) -> RenderPushArgument {
+        let input_path = temp_dir.path().join("template");
+        let destination_path = temp_dir.path().join("destination");
+        fs::create_dir_all(&input_path).unwrap();
+        fs::create_dir_all(&destination_path).unwrap();
+        fs::write(input_path.join("a.txt"), "CREATORLY.name").unwrap();
+        fs::write(input_path.join("b.txt"), "CREATORLY.name").unwrap();
+        fs::write(destination_path.join("b.txt"), "existing").unwrap();
+
+        let mut template_configuration = TemplateConfiguration::new();
+        template_configuration.templates.push(TemplateConfigurationItem::new(
+            input_path.join("creatorly.yml"),
+            TemplateSpecification::new(),
+            vec![input_path.join("a.txt"), input_path.join("b.txt")],
+        ));
+        template_configuration
+            .answers
+            .insert("name".to_string(), Answer::from("Max"));
+
+        RenderPushArgument {
+            input_path,
+            destination_path,
+            template_configuration,
+            options,
+            additional_files: vec![(PathBuf::from("record.yml"), "answers".to_string())],
+        }
+    }
+
+    fn create_template_engine(user_interaction: MockUserInteractionInterface) -> Arc<TemplateEngine> {
+        Arc::new(TemplateEngine::new_with_default_template_renderer(
+            Arc::new(FileSystem {}),
+            Arc::new(user_interaction),
+        ))
+    }
+
+    #[tokio::test]
+    async fn test_render_and_push_should_refuse_destination_with_files() {
+        // arrange
+        let temp_dir = TempDir::new("test_refuse_destination").unwrap();
+        let args = create_render_push_argument(&temp_dir, RenderOptions::default());
+        let destination_path = args.destination_path.clone();
+        let sut = create_template_engine(MockUserInteractionInterface::new());
+
+        // act
+        let result = sut.render_and_push(args).await;
+
+        // assert
+        assert!(result.is_err());
+        assert!(!destination_path.join("a.txt").exists());
+    }
+
+    #[tokio::test]
+    async fn test_render_and_push_should_skip_existing_files() {
+        // arrange
+        let temp_dir = TempDir::new("test_skip_existing_files").unwrap();
+        let options = RenderOptions {
+            on_conflict: Some(ConflictStrategy::Skip),
+            ..Default::default()
+        };
+        let args = create_render_push_argument(&temp_dir, options);
+        let destination_path = args.destination_path.clone();
+        let mut user_interaction = MockUserInteractionInterface::new();
+        user_interaction
+            .expect_print()
+            .withf(|message| message.starts_with("Skip existing file") && message.ends_with("b.txt"))
+            .times(1)
+            .returning(|_| ());
+        let sut = create_template_engine(user_interaction);
+
+        // act
+        sut.render_and_push(args).await.unwrap();
+
+        // assert
+        assert_eq!(fs::read_to_string(destination_path.join("a.txt")).unwrap(), "Max");
+        assert_eq!(fs::read_to_string(destination_path.join("b.txt")).unwrap(), "existing");
+        assert_eq!(
+            fs::read_to_string(destination_path.join("record.yml")).unwrap(),
+            "answers"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_render_and_push_should_fail_for_existing_files_before_writing() {
+        // arrange
+        let temp_dir = TempDir::new("test_fail_existing_files").unwrap();
+        let options = RenderOptions {
+            on_conflict: Some(ConflictStrategy::Fail),
+            ..Default::default()
+        };
+        let args = create_render_push_argument(&temp_dir, options);
+        let destination_path = args.destination_path.clone();
+        let mut user_interaction = MockUserInteractionInterface::new();
+        user_interaction.expect_print_error().times(1).returning(|_| ());
+        let sut = create_template_engine(user_interaction);
+
+        // act
+        let result = sut.render_and_push(args).await;
+
+        // assert
+        assert!(result.is_err());
+        assert!(!destination_path.join("a.txt").exists());
+    }
+
+    #[tokio::test]
+    async fn test_plan_should_show_refused_destination_and_additional_files() {
+        // arrange
+        let temp_dir = TempDir::new("test_plan_refused_destination").unwrap();
+        let args = create_render_push_argument(&temp_dir, RenderOptions::default());
+        let destination_path = args.destination_path.clone();
+        let sut = create_template_engine(MockUserInteractionInterface::new());
+
+        // act
+        let plan = sut.plan(&args).await.unwrap();
+
+        // assert
+        assert!(plan.refusal.unwrap().starts_with("The destination"));
+        assert_eq!(plan.files.len(), 3);
+        assert_eq!(plan.files[0].source_path, Some(PathBuf::from("a.txt")));
+        assert_eq!(plan.files[0].conflict, None);
+        assert_eq!(plan.files[1].conflict, Some(ConflictStrategy::Fail));
+        assert_eq!(plan.files[2].source_path, None);
+        assert_eq!(plan.files[2].target_path, destination_path.join("record.yml"));
+        assert_eq!(plan.files[2].action, PlannedAction::Write);
+        assert!(!destination_path.join("a.txt").exists());
+    }
+
+    #[tokio::test]
+    async fn test_plan_should_show_strategy_for_existing_files() {
+        // arrange
+        let temp_dir = TempDir::new("test_plan_existing_files").unwrap();
+        let options = RenderOptions {
+            on_conflict: Some(ConflictStrategy::Skip),
+            ..Default::default()
+        };
+        let args = create_render_push_argument(&temp_dir, options);
+        let sut = create_template_engine(MockUserInteractionInterface::new());
+
+        // act
+        let plan = sut.plan(&args).await.unwrap();
+
+        // assert
+        assert_eq!(plan.refusal, None);
+        assert_eq!(plan.files[1].conflict, Some(ConflictStrategy::Skip));
+    }
+
+    #[tokio::test]
+    async fn test_plan_should_refuse_existing_files_for_fail_strategy() {
+        // arrange
+        let temp_dir = TempDir::new("test_plan_fail_existing_files").unwrap();
+        let options = RenderOptions {
+            on_conflict: Some(ConflictStrategy::Fail),
+            ..Default::default()
+        };
+        let args = create_render_push_argument(&temp_dir, options);
+        let sut = create_template_engine(MockUserInteractionInterface::new());
+
+        // act
+        let plan = sut.plan(&args).await.unwrap();
+
+        // assert
+        assert!(plan.refusal.unwrap().starts_with("1 files exist already"));
+    }
+
+    #[tokio::test]
+    async fn test_plan_should_have_no_conflicts_with_clean() {
+        // arrange
+        let temp_dir = TempDir::new("test_plan_clean").unwrap();
+        let options = RenderOptions {
+            clean: true,
+            ..Default::default()
+        };
+        let args = create_render_push_argument(&temp_dir, options);
+        let sut = create_template_engine(MockUserInteractionInterface::new());
+
+        // act
+        let plan = sut.plan(&args).await.unwrap();
+
+        // assert
+        assert!(plan.clean);
+        assert_eq!(plan.refusal, None);
+        assert!(plan.files.iter().all(|file| file.conflict.is_none()));
+    }
+
+    #[tokio::test]
+    async fn test_render_and_push_should_keep_destination_if_a_file_fails() {
+        // arrange
+        let temp_dir = TempDir::new("test_keep_destination").unwrap();
+        let options = RenderOptions {
+            on_conflict: Some(ConflictStrategy::Overwrite),
+            ..Default::default()
+        };
+        let mut args = create_render_push_argument(&temp_dir, options);
+        let missing_file = args.input_path.join("missing.txt");
+        args.template_configuration.templates[0].file_list.push(missing_file);
+        let destination_path = args.destination_path.clone();
+        let mut user_interaction = MockUserInteractionInterface::new();
+        user_interaction.expect_print().returning(|_| ());
+        let sut = create_template_engine(user_interaction);
+
+        // act
+        let result = sut.render_and_push(args).await;
+
+        // assert
+        assert!(result.is_err());
+        assert!(!destination_path.join("a.txt").exists());
+        assert!(!destination_path.join("record.yml").exists());
+        assert_eq!(fs::read_to_string(destination_path.join("b.txt")).unwrap(), "existing");
+        assert_eq!(fs::read_dir(temp_dir.path()).unwrap().count(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_render_and_push_should_return_errors_of_all_files() {
+        // arrange
+        let temp_dir = TempDir::new("test_errors_of_all_files").unwrap();
+        let mut args = create_render_push_argument(&temp_dir, RenderOptions::default());
+        fs::remove_file(args.destination_path.join("b.txt")).unwrap();
+        let file_list = &mut args.template_configuration.templates[0].file_list;
+        file_list.push(args.input_path.join("missing1.txt"));
+        file_list.push(args.input_path.join("missing2.txt"));
+        let destination_path = args.destination_path.clone();
+        let sut = create_template_engine(MockUserInteractionInterface::new());
+
+        // act
+        let result = sut.render_and_push(args).await;
+
+        // assert
+        let error = result.err().unwrap().to_string();
+        assert!(error.starts_with("2 files could not be generated"));
+        assert!(error.contains("\n  missing1.txt: "));
+        assert!(error.contains("\n  missing2.txt: "));
+        assert!(!destination_path.join("a.txt").exists());
+    }
+
+    #[tokio::test]
+    async fn test_render_and_push_should_fail_for_render_error_in_strict_mode() {
+        // arrange
+        let temp_dir = TempDir::new("test_strict_render_error").unwrap();
+        let options = RenderOptions {
+            strict: true,
+            ..Default::default()
+        };
+        let args = create_render_push_argument(&temp_dir, options);
+        fs::remove_file(args.destination_path.join("b.txt")).unwrap();
+        fs::write(args.input_path.join("b.txt"), "CREATORLY.endif\n").unwrap();
+        let destination_path = args.destination_path.clone();
+        let sut = create_template_engine(MockUserInteractionInterface::new());
+
+        // act
+        let result = sut.render_and_push(args).await;
+
+        // assert
+        let error = result.err().unwrap().to_string();
+        assert!(error.contains("b.txt: Line 1: CREATORLY.endif without an open block"));
+        assert!(!destination_path.join("a.txt").exists());
+    }
+
+    #[tokio::test]
+    async fn test_render_and_push_should_render_all_files_with_one_job() {
+        // arrange
+        let temp_dir = TempDir::new("test_one_job").unwrap();
+        let options = RenderOptions {
+            clean: true,
+            jobs: Some(1),
+            ..Default::default()
+        };
+        let mut args = create_render_push_argument(&temp_dir, options);
+        for index in 0..5 {
+            let file = args.input_path.join(format!("file{}.txt", index));
+            fs::write(&file, "CREATORLY.name").unwrap();
+            args.template_configuration.templates[0].file_list.push(file);
+        }
+        let destination_path = args.destination_path.clone();
+        let sut = create_template_engine(MockUserInteractionInterface::new());
+
+        // act
+        sut.render_and_push(args).await.unwrap();
+
+        // assert
+        assert_eq!(fs::read_dir(&destination_path).unwrap().count(), 8);
+        assert_eq!(fs::read_to_string(destination_path.join("file4.txt")).unwrap(), "Max");
+    }
+
+    #[tokio::test]
+    async fn test_render_and_push_should_not_replace_placeholders_in_answers() {
+        // arrange
+        let temp_dir = TempDir::new("test_answers_with_placeholders").unwrap();
+        let options = RenderOptions {
+            clean: true,
+            ..Default::default()
+        };
+        let mut args = create_render_push_argument(&temp_dir, options);
+        let answers = &mut args.template_configuration.answers;
+        answers.insert("title".to_string(), Answer::from("CREATORLY.name"));
+        answers.insert("entries".to_string(), Answer::List(vec!["CREATORLY.title".to_string()]));
+        fs::write(
+            args.input_path.join("a.txt"),
+            "CREATORLY.title
+CREATORLY.for.entry in entries
+CREATORLY.entry
+CREATORLY.endfor
+",
+        )
+        .unwrap();
+        let destination_path = args.destination_path.clone();
+        let sut = create_template_engine(MockUserInteractionInterface::new());
+
+        // act
+        sut.render_and_push(args).await.unwrap();
+
+        // assert
+        assert_eq!(
+            fs::read_to_string(destination_path.join("a.txt")).unwrap(),
+            "CREATORLY.name
+CREATORLY.title
+"
+        );
+    }
+
+    #[test]
+    fn test_get_jobs_should_return_at_least_one() {
+        let options = RenderOptions {
+            jobs: Some(0),
+            ..Default::default()
+        };
+
+        assert_eq!(options.get_jobs(), 1);
+        assert!(RenderOptions::default().get_jobs() >= 1);
     }
 }
@@ -1,5 +1,12 @@
+use globset::{GlobBuilder, GlobSet, GlobSetBuilder};
 use indexmap::IndexMap;
+use regex::Regex;
 use serde::{Deserialize, Serialize};
+use serde_yaml::Value;
+
+use super::answer::Answer;
+use super::computed_expression::ComputedExpression;
+use super::condition::Condition;
 
 /// The template specification. It contains the questions, which are asked.
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
@@ -12,6 +19,25 @@ pub struct TemplateSpecification {
     #[serde(skip_serializing_if = "Option::is_none")]
     placeholder_delimiter: Option<String>,
 
+    /// Represents whether a backslash before a placeholder writes it literally. For example `\CREATORLY.xyz` is written
+    /// as `CREATORLY.xyz`. It is off by default, so that a backslash before a placeholder like in a Windows path is
+    /// kept as it is.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    placeholder_escape: bool,
+
+    /// Represents the engine, which renders the files. For example "jinja".
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    engine: Option<RenderEngine>,
+
+    /// Represents the glob patterns of the files, which are copied without rendering their content.
+    /// For example "vendor/**/*.js".
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    copy_without_render: Vec<String>,
+
+    /// Represents the glob patterns of the files, whose names are not rendered.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    keep_file_names: Vec<String>,
+
     /// Represents a list of questions for a template specification.
     pub placeholders: IndexMap<String, TemplateSpecificationItemType>,
 }
@@ -25,6 +51,10 @@ impl TemplateSpecification {
         Self {
             placeholder_id: None,
             placeholder_delimiter: None,
+            placeholder_escape: false,
+            engine: None,
+            copy_without_render: vec![],
+            keep_file_names: vec![],
             placeholders: IndexMap::new(),
         }
     }
@@ -34,6 +64,10 @@ impl TemplateSpecification {
         Self {
             placeholder_id: Some(placeholder_id),
             placeholder_delimiter: Some(delimeter),
+            placeholder_escape: false,
+            engine: None,
+            copy_without_render: vec![],
+            keep_file_names: vec![],
             placeholders: IndexMap::new(),
         }
     }
@@ -51,6 +85,36 @@ impl TemplateSpecification {
             None => Self::DELIMITER.to_string(),
         }
     }
+
+    /// Returns true, if a backslash before a placeholder, a block tag or an include writes it literally.
+    pub fn get_placeholder_escape(&self) -> bool {
+        self.placeholder_escape
+    }
+
+    pub fn get_engine(&self) -> RenderEngine {
+        self.engine.clone().unwrap_or_default()
+    }
+
+    /// Returns the files, which are copied without rendering their content.
+    pub fn get_copy_without_render(&self) -> Result<GlobSet, String> {
+        create_glob_set(&self.copy_without_render).map_err(|error| format!("Invalid copy_without_render: {}", error))
+    }
+
+    /// Returns the files, whose names are not rendered.
+    pub fn get_keep_file_names(&self) -> Result<GlobSet, String> {
+        create_glob_set(&self.keep_file_names).map_err(|error| format!("Invalid keep_file_names: {}", error))
+    }
+}
+
+/// Creates the set of the glob patterns like `vendor/**/*.js`, which are matched against the relative path of a file.
+/// `*` and `?` do not match `/`, so that only `**` matches any directories.
+fn create_glob_set(patterns: &[String]) -> Result<GlobSet, globset::Error> {
+    let mut builder = GlobSetBuilder::new();
+    for pattern in patterns {
+        builder.add(GlobBuilder::new(pattern).literal_separator(true).build()?);
+    }
+
+    builder.build()
 }
 
 impl Default for TemplateSpecification {
@@ -59,7 +123,22 @@ impl Default for TemplateSpecification {
     }
 }
 
+/// The engine, which renders the files of a template.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RenderEngine {
+    /// Replaces the placeholders like `CREATORLY.project_name` (see `RegexTemplateRenderer`).
+    #[default]
+    Regex,
+
+    /// Renders the files as Jinja templates with `{{ }}`, `{% if %}` and `{% for %}` (see `JinjaTemplateRenderer`).
+    Jinja,
+}
+
 /// The type of the template specification item.
+///
+/// The short forms are a string (free text with a default) and a list of strings (choose one of them).
+/// The long form is a map, which describes the placeholder in detail (see `PlaceholderSpecification`).
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(untagged)]
 pub enum TemplateSpecificationItemType {
@@ -68,4 +147,534 @@ pub enum TemplateSpecificationItemType {
 
     /// A multiple choice item.
     MultipleChoice(Vec<String>),
+
+    /// A placeholder with a detailed specification.
+    Detailed(Box<PlaceholderSpecification>),
+}
+
+impl TemplateSpecificationItemType {
+    /// Returns the type of the value.
+    pub fn get_type(&self) -> PlaceholderType {
+        match self {
+            Self::SingleChoice(_) => PlaceholderType::String,
+            Self::MultipleChoice(_) => PlaceholderType::Choice,
+            Self::Detailed(specification) => specification.value_type.clone(),
+        }
+    }
+
+    /// Returns the text, which is shown when the answer is prompted.
+    pub fn get_prompt(&self, key: &str) -> String {
+        match self {
+            Self::Detailed(specification) => specification.prompt.clone().unwrap_or_else(|| format!("{}: ", key)),
+            _ => format!("{}: ", key),
+        }
+    }
+
+    /// Returns the help text of the placeholder.
+    pub fn get_help(&self) -> Option<&str> {
+        match self {
+            Self::Detailed(specification) => specification.help.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Returns the allowed choices, it is empty if the value is not chosen from a list.
+    pub fn get_choices(&self) -> &[String] {
+        match self {
+            Self::SingleChoice(_) => &[],
+            Self::MultipleChoice(choices) => choices,
+            Self::Detailed(specification) => &specification.choices,
+        }
+    }
+
+    /// Returns the default value in the format of an answer.
+    ///
+    /// Without a declared default it is an empty string, `false` for a boolean, `0` for an integer and the first
+    /// entry for a choice.
+    pub fn get_default(&self) -> String {
+        let declared_default = match self {
+            Self::SingleChoice(default) => Some(default.clone()),
+            Self::MultipleChoice(_) => None,
+            Self::Detailed(specification) => specification.default.as_ref().map(value_to_answer),
+        };
+
+        if let Some(declared_default) = declared_default {
+            return declared_default;
+        }
+
+        match self.get_type() {
+            PlaceholderType::String | PlaceholderType::MultiChoice | PlaceholderType::List => String::new(),
+            PlaceholderType::Bool => false.to_string(),
+            PlaceholderType::Integer => 0.to_string(),
+            PlaceholderType::Choice => self.get_choices().first().cloned().unwrap_or_default(),
+        }
+    }
+
+    /// Returns the validation rules of the placeholder.
+    pub fn get_validation(&self) -> Option<&ValidationRules> {
+        match self {
+            Self::Detailed(specification) => specification.validation.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// Returns the parsed condition of the placeholder, the placeholder is always asked if it has none.
+    pub fn get_condition(&self) -> core::result::Result<Option<Condition>, String> {
+        match self {
+            Self::Detailed(specification) => specification.when.as_deref().map(Condition::parse).transpose(),
+            _ => Ok(None),
+        }
+    }
+
+    /// Returns the parsed expression of a computed placeholder, it is none if the placeholder is asked.
+    pub fn get_computed(&self) -> core::result::Result<Option<ComputedExpression>, String> {
+        match self {
+            Self::Detailed(specification) => specification
+                .computed
+                .as_deref()
+                .map(ComputedExpression::parse)
+                .transpose(),
+            _ => Ok(None),
+        }
+    }
+
+    /// Checks the given answer against the type and the validation rules of the placeholder and returns it in its
+    /// normalized form.
+    ///
+    /// Booleans are normalized to `true` or `false` and the entries of a multi choice are joined by `, `.
+    pub fn parse_answer(&self, answer: &str) -> core::result::Result<String, String> {
+        let answer = self.parse_value(answer)?;
+
+        if let Some(validation) = self.get_validation() {
+            validation.validate(&answer)?;
+        }
+
+        Ok(answer)
+    }
+
+    fn parse_value(&self, answer: &str) -> core::result::Result<String, String> {
+        let choices = self.get_choices();
+        match self.get_type() {
+            PlaceholderType::String => Ok(answer.to_string()),
+            PlaceholderType::Bool => match answer.trim().to_lowercase().as_str() {
+                "true" | "yes" | "y" | "1" => Ok(true.to_string()),
+                "false" | "no" | "n" | "0" => Ok(false.to_string()),
+                _ => Err("The answer has to be a boolean (true or false)".to_string()),
+            },
+            PlaceholderType::Integer => answer
+                .trim()
+                .parse::<i64>()
+                .map(|value| value.to_string())
+                .map_err(|_| "The answer has to be an integer".to_string()),
+            PlaceholderType::Choice => {
+                if choices.iter().any(|choice| choice == answer) {
+                    Ok(answer.to_string())
+                } else {
+                    Err(format!("Allowed choices are: {}", choices.join(", ")))
+                }
+            }
+            PlaceholderType::MultiChoice => {
+                let entries = split_multi_choice_answer(answer);
+                if entries.iter().any(|entry| !choices.contains(entry)) {
+                    return Err(format!("Allowed choices are: {}", choices.join(", ")));
+                }
+
+                Ok(entries.join(", "))
+            }
+            PlaceholderType::List => Ok(split_multi_choice_answer(answer).join(", ")),
+        }
+    }
+
+    /// Converts a normalized answer (see `parse_answer`) into the answer, which is stored in the configuration.
+    /// The answers of a multi choice and a list are stored as a list.
+    pub fn to_answer(&self, answer: String) -> Answer {
+        match self.get_type() {
+            PlaceholderType::MultiChoice | PlaceholderType::List => Answer::List(split_multi_choice_answer(&answer)),
+            _ => Answer::Single(answer),
+        }
+    }
+}
+
+/// Splits the answer of a multi choice or a list placeholder into its entries.
+pub fn split_multi_choice_answer(answer: &str) -> Vec<String> {
+    answer
+        .split(',')
+        .map(|entry| entry.trim().to_string())
+        .filter(|entry| !entry.is_empty())
+        .collect()
+}
+
+/// Converts a YAML value into the format of an answer.
+fn value_to_answer(value: &Value) -> String {
+    match value {
+        Value::String(value) => value.clone(),
+        Value::Bool(value) => value.to_string(),
+        Value::Number(value) => value.to_string(),
+        Value::Sequence(values) => values.iter().map(value_to_answer).collect::<Vec<_>>().join(", "),
+        _ => String::new(),
+    }
+}
+
+/// The detailed specification of a placeholder (long form).
+///
+/// ```yaml
+/// use_docker:
+///   type: bool
+///   prompt: "Do you want to use docker?"
+///   help: "Adds a Dockerfile to the project"
+///   default: true
+/// ```
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct PlaceholderSpecification {
+    /// The type of the value.
+    #[serde(rename = "type", default)]
+    pub value_type: PlaceholderType,
+
+    /// The text, which is shown when the answer is prompted. The key of the placeholder is used if it is not set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prompt: Option<String>,
+
+    /// An additional description, which is shown before the prompt.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub help: Option<String>,
+
+    /// The default value. For a multi choice it is a list of the preselected choices.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default: Option<Value>,
+
+    /// The allowed choices for the types `choice` and `multi-choice`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub choices: Vec<String>,
+
+    /// The rules, which an answer has to fulfill.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub validation: Option<ValidationRules>,
+
+    /// A condition over the previous answers, the placeholder is only asked if it is true (see `Condition`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub when: Option<String>,
+
+    /// An expression, which computes the value from other answers instead of asking it (see `ComputedExpression`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub computed: Option<String>,
+}
+
+/// The validation rules of a placeholder.
+///
+/// ```yaml
+/// project_name:
+///   validation:
+///     pattern: "[a-z][a-z0-9_]*"
+///     max_length: 64
+///     message: "Use lowercase letters, digits and underscores"
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct ValidationRules {
+    /// A regular expression, which has to match the whole answer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pattern: Option<String>,
+
+    /// The minimal number of characters.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_length: Option<usize>,
+
+    /// The maximal number of characters.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_length: Option<usize>,
+
+    /// The minimal value of a number (inclusive).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min: Option<i64>,
+
+    /// The maximal value of a number (inclusive).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max: Option<i64>,
+
+    /// The message, which is shown instead of the default message if the answer is invalid.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+impl ValidationRules {
+    /// Returns the compiled pattern, which is anchored to match the whole answer.
+    pub fn get_pattern(&self) -> core::result::Result<Option<Regex>, String> {
+        let Some(pattern) = &self.pattern else {
+            return Ok(None);
+        };
+
+        Regex::new(&format!("^(?:{})$", pattern))
+            .map(Some)
+            .map_err(|e| format!("Invalid pattern {}: {}", pattern, e))
+    }
+
+    /// Checks the answer against the rules.
+    pub fn validate(&self, answer: &str) -> core::result::Result<(), String> {
+        self.check(answer)
+            .map_err(|error| self.message.clone().unwrap_or(error))
+    }
+
+    fn check(&self, answer: &str) -> core::result::Result<(), String> {
+        if let Some(pattern) = self.get_pattern()? {
+            if !pattern.is_match(answer) {
+                return Err(format!(
+                    "The answer has to match the pattern {}",
+                    self.pattern.as_deref().unwrap_or_default()
+                ));
+            }
+        }
+
+        let length = answer.chars().count();
+        if let Some(min_length) = self.min_length {
+            if length < min_length {
+                return Err(format!("The answer has to be at least {} characters long", min_length));
+            }
+        }
+
+        if let Some(max_length) = self.max_length {
+            if length > max_length {
+                return Err(format!("The answer has to be at most {} characters long", max_length));
+            }
+        }
+
+        if self.min.is_none() && self.max.is_none() {
+            return Ok(());
+        }
+
+        let value = answer
+            .trim()
+            .parse::<i64>()
+            .map_err(|_| "The answer has to be a number".to_string())?;
+        if let Some(min) = self.min {
+            if value < min {
+                return Err(format!("The answer has to be at least {}", min));
+            }
+        }
+
+        if let Some(max) = self.max {
+            if value > max {
+                return Err(format!("The answer has to be at most {}", max));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// The type of the value of a placeholder.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum PlaceholderType {
+    /// A free text.
+    #[default]
+    String,
+
+    /// A yes/no question, which is rendered as `true` or `false`.
+    Bool,
+
+    /// An integer number.
+    Integer,
+
+    /// One entry of a list of choices.
+    Choice,
+
+    /// Several entries of a list of choices, which are rendered separated by `, `.
+    MultiChoice,
+
+    /// A list of free texts, which are entered separated by `,` and rendered separated by `, `.
+    List,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+
+    #[test]
+    fn test_get_copy_without_render_should_match_wildcards() {
+        let specification: TemplateSpecification = serde_yaml::from_str(
+            "copy_without_render: ['vendor/**/*.js', 'fixtures/*.{sql,csv}', 'logo?.[!a-z]*', '**/node_modules/**']\n\
+             placeholders: {}\n",
+        )
+        .unwrap();
+
+        let glob_set = specification.get_copy_without_render().unwrap();
+
+        assert!(glob_set.is_match(Path::new("vendor/jquery.js")));
+        assert!(glob_set.is_match(Path::new("vendor/lib/min/jquery.js")));
+        assert!(glob_set.is_match(Path::new("fixtures/users.csv")));
+        assert!(glob_set.is_match(Path::new("logo1.PNG")));
+        assert!(glob_set.is_match(Path::new("web/node_modules/index.js")));
+        assert!(!glob_set.is_match(Path::new("src/vendor/jquery.js")));
+        assert!(!glob_set.is_match(Path::new("fixtures/data/users.sql")));
+        assert!(!glob_set.is_match(Path::new("logo1.png")));
+        assert!(!TemplateSpecification::new()
+            .get_keep_file_names()
+            .unwrap()
+            .is_match(Path::new("file.txt")));
+    }
+
+    #[test]
+    fn test_deserialize_should_support_short_and_long_form() {
+        let yaml = r#"
+placeholders:
+  project_name: "Demo"
+  license: ["MIT", "BSD"]
+  use_docker:
+    type: bool
+    prompt: "Use docker?"
+    help: "Adds a Dockerfile"
+    default: true
+  features:
+    type: multi-choice
+    choices: ["logging", "metrics"]
+    default: ["metrics"]
+"#;
+
+        let specification: TemplateSpecification = serde_yaml::from_str(yaml).unwrap();
+
+        let project_name = &specification.placeholders["project_name"];
+        assert_eq!(project_name.get_type(), PlaceholderType::String);
+        assert_eq!(project_name.get_prompt("project_name"), "project_name: ");
+        assert_eq!(project_name.get_default(), "Demo");
+
+        let license = &specification.placeholders["license"];
+        assert_eq!(license.get_type(), PlaceholderType::Choice);
+        assert_eq!(license.get_default(), "MIT");
+
+        let use_docker = &specification.placeholders["use_docker"];
+        assert_eq!(use_docker.get_type(), PlaceholderType::Bool);
+        assert_eq!(use_docker.get_prompt("use_docker"), "Use docker?");
+        assert_eq!(use_docker.get_help(), Some("Adds a Dockerfile"));
+        assert_eq!(use_docker.get_default(), "true");
+
+        let features = &specification.placeholders["features"];
+        assert_eq!(features.get_type(), PlaceholderType::MultiChoice);
+        assert_eq!(features.get_choices(), &["logging", "metrics"]);
+        assert_eq!(features.get_default(), "metrics");
+    }
+
+    #[test]
+    fn test_deserialize_should_fail_for_unknown_field() {
+        let yaml = r#"
+placeholders:
+  project_name:
+    promt: "Name?"
+"#;
+
+        let result = serde_yaml::from_str::<TemplateSpecification>(yaml);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_parse_answer_should_check_type() {
+        let item = |value_type: PlaceholderType| {
+            TemplateSpecificationItemType::Detailed(Box::new(PlaceholderSpecification {
+                value_type,
+                prompt: None,
+                help: None,
+                default: None,
+                choices: vec!["a".to_string(), "b".to_string()],
+                validation: None,
+                when: None,
+                computed: None,
+            }))
+        };
+
+        assert_eq!(item(PlaceholderType::Bool).parse_answer("yes"), Ok("true".to_string()));
+        assert!(item(PlaceholderType::Bool).parse_answer("maybe").is_err());
+        assert_eq!(
+            item(PlaceholderType::Integer).parse_answer(" 42 "),
+            Ok("42".to_string())
+        );
+        assert!(item(PlaceholderType::Integer).parse_answer("4.2").is_err());
+        assert_eq!(item(PlaceholderType::Choice).parse_answer("b"), Ok("b".to_string()));
+        assert!(item(PlaceholderType::Choice).parse_answer("c").is_err());
+        assert_eq!(
+            item(PlaceholderType::MultiChoice).parse_answer("b,a"),
+            Ok("b, a".to_string())
+        );
+        assert!(item(PlaceholderType::MultiChoice).parse_answer("a, c").is_err());
+        assert_eq!(
+            item(PlaceholderType::List).parse_answer("web,, db "),
+            Ok("web, db".to_string())
+        );
+    }
+
+    #[test]
+    fn test_to_answer_should_return_list_for_multi_choice_and_list() {
+        let specification: TemplateSpecification =
+            serde_yaml::from_str("placeholders:\n  name: Demo\n  services:\n    type: list\n    default: [web, db]\n")
+                .unwrap();
+        let name = &specification.placeholders["name"];
+        let services = &specification.placeholders["services"];
+
+        assert_eq!(name.to_answer("web, db".to_string()), Answer::from("web, db"));
+        assert_eq!(services.get_default(), "web, db");
+        assert_eq!(
+            services.to_answer(services.get_default()),
+            Answer::List(vec!["web".to_string(), "db".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_parse_answer_should_check_validation_rules() {
+        let yaml = r#"
+placeholders:
+  project_name:
+    validation:
+      pattern: "[a-z][a-z0-9_]*"
+      min_length: 3
+      max_length: 8
+  port:
+    type: integer
+    validation:
+      min: 1024
+      max: 65535
+      message: "Use a port between 1024 and 65535"
+"#;
+
+        let specification: TemplateSpecification = serde_yaml::from_str(yaml).unwrap();
+        let project_name = &specification.placeholders["project_name"];
+        let port = &specification.placeholders["port"];
+
+        assert_eq!(project_name.parse_answer("my_app"), Ok("my_app".to_string()));
+        assert_eq!(
+            project_name.parse_answer("my app"),
+            Err("The answer has to match the pattern [a-z][a-z0-9_]*".to_string())
+        );
+        assert_eq!(
+            project_name.parse_answer("ab"),
+            Err("The answer has to be at least 3 characters long".to_string())
+        );
+        assert_eq!(
+            project_name.parse_answer("abcdefghi"),
+            Err("The answer has to be at most 8 characters long".to_string())
+        );
+        assert_eq!(port.parse_answer("8080"), Ok("8080".to_string()));
+        assert_eq!(
+            port.parse_answer("80"),
+            Err("Use a port between 1024 and 65535".to_string())
+        );
+    }
+
+    #[test]
+    fn test_get_condition_should_parse_when() {
+        let yaml = r#"
+placeholders:
+  ci_provider: ["github", "gitlab"]
+  gitlab_runner_tag:
+    when: "ci_provider == 'gitlab'"
+"#;
+
+        let specification: TemplateSpecification = serde_yaml::from_str(yaml).unwrap();
+        let ci_provider = &specification.placeholders["ci_provider"];
+        let gitlab_runner_tag = &specification.placeholders["gitlab_runner_tag"];
+
+        assert_eq!(ci_provider.get_condition(), Ok(None));
+        let condition = gitlab_runner_tag.get_condition().unwrap().unwrap();
+        assert_eq!(condition.get_placeholders(), vec!["ci_provider"]);
+    }
 }
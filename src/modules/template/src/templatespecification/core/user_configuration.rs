@@ -0,0 +1,11 @@
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+
+/// The configuration of the user, which applies to all templates.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+pub struct UserConfiguration {
+    /// The default answers of the placeholders, they replace the defaults of the templates.
+    /// For example `author_name` or `author_email`.
+    #[serde(default)]
+    pub default_answers: IndexMap<String, String>,
+}
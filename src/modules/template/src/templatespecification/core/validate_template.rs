@@ -1,10 +1,27 @@
+use super::computed_expression::sort_by_dependencies;
 use super::template_configuration::TemplateConfiguration;
+use super::template_specification::{PlaceholderType, RenderEngine, TemplateSpecificationItemType};
 use common::core::errors::{Error, Result};
 
 type ValidateFunction = fn(&TemplateConfiguration) -> Result<()>;
 
+/// The names of the block tags and of the include of the regex engine, which can not be placeholder keys.
+const RESERVED_KEYS: [&str; 9] = [
+    "if", "for", "else", "endif", "endfor", "raw", "endraw", "rawfile", "include",
+];
+
+/// The tags, which end at a word boundary, so that `CREATORLY.raw-data` is read as the tag `CREATORLY.raw`.
+const TAGS_WITH_WORD_BOUNDARY: [&str; 6] = ["else", "endif", "endfor", "raw", "endraw", "rawfile"];
+
 // list of validation funcitons
-const VALIDATION_FUNCTIONS: [ValidateFunction; 1] = [have_empty_file_list];
+const VALIDATION_FUNCTIONS: [ValidateFunction; 6] = [
+    have_empty_file_list,
+    have_invalid_placeholders,
+    have_reserved_placeholder_keys,
+    have_invalid_conditions,
+    have_invalid_computed_placeholders,
+    have_invalid_file_patterns,
+];
 
 /// Validates the template configuration.
 /// It checks if the configuration is valid.
@@ -19,6 +36,43 @@ pub fn validate_template_configuration(template: &TemplateConfiguration) -> Resu
     Ok(())
 }
 
+/// Returns the warnings of the template configuration, which do not prevent the generation.
+///
+/// It warns about placeholder keys, which are a prefix of another key, for example `name` and `name_full`.
+/// The regex renderer always replaces the longest key, so `CREATORLY.name_full` can not be used for `name`
+/// followed by `_full`.
+pub fn get_template_configuration_warnings(template: &TemplateConfiguration) -> Vec<String> {
+    let mut warnings = vec![];
+
+    for item in &template.templates {
+        let specification = &item.template_specification;
+        if specification.get_engine() != RenderEngine::Regex {
+            continue;
+        }
+
+        let delimiter = specification.get_placeholder_delimiter();
+        let keys: Vec<&String> = specification.placeholders.keys().collect();
+        for key in &keys {
+            for other_key in &keys {
+                if key != other_key && other_key.starts_with(key.as_str()) {
+                    warnings.push(format!(
+                        "Placeholder {} is a prefix of placeholder {} in {}, {}{}{} is always replaced by the answer of {}",
+                        key,
+                        other_key,
+                        item.root_path.display(),
+                        specification.get_placeholder_id(),
+                        delimiter,
+                        other_key,
+                        other_key
+                    ));
+                }
+            }
+        }
+    }
+
+    warnings
+}
+
 fn have_empty_file_list(template: &TemplateConfiguration) -> Result<()> {
     for item in &template.templates {
         if item.file_list.is_empty() {
@@ -31,3 +85,332 @@ fn have_empty_file_list(template: &TemplateConfiguration) -> Result<()> {
 
     Ok(())
 }
+
+fn have_invalid_placeholders(template: &TemplateConfiguration) -> Result<()> {
+    for item in &template.templates {
+        for (key, placeholder) in &item.template_specification.placeholders {
+            let value_type = placeholder.get_type();
+            let is_choice = value_type == PlaceholderType::Choice || value_type == PlaceholderType::MultiChoice;
+            if is_choice && placeholder.get_choices().is_empty() {
+                return Err(Error::new(format!(
+                    "Placeholder {} has no choices in {}",
+                    key,
+                    item.root_path.display()
+                )));
+            }
+
+            let TemplateSpecificationItemType::Detailed(specification) = placeholder else {
+                continue;
+            };
+
+            if let Some(validation) = &specification.validation {
+                validation.get_pattern().map_err(|error| {
+                    Error::new(format!(
+                        "Placeholder {} in {}: {}",
+                        key,
+                        item.root_path.display(),
+                        error
+                    ))
+                })?;
+            }
+
+            if specification.default.is_none() {
+                continue;
+            }
+
+            if let Err(error) = placeholder.parse_answer(&placeholder.get_default()) {
+                return Err(Error::with_advice(
+                    format!(
+                        "Invalid default value of placeholder {} in {}",
+                        key,
+                        item.root_path.display()
+                    ),
+                    error,
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks that the placeholder keys of the regex engine can not be read as a block tag or an include.
+fn have_reserved_placeholder_keys(template: &TemplateConfiguration) -> Result<()> {
+    for item in &template.templates {
+        let specification = &item.template_specification;
+        if specification.get_engine() != RenderEngine::Regex {
+            continue;
+        }
+
+        for key in specification.placeholders.keys() {
+            let is_reserved = RESERVED_KEYS.contains(&key.as_str())
+                || TAGS_WITH_WORD_BOUNDARY
+                    .iter()
+                    .any(|tag| key.starts_with(&format!("{}-", tag)));
+            if is_reserved {
+                return Err(Error::with_advice(
+                    format!(
+                        "Placeholder {} in {} is read as a block tag or an include",
+                        key,
+                        item.root_path.display()
+                    ),
+                    format!(
+                        "Rename the placeholder, the names {} are reserved",
+                        RESERVED_KEYS.join(", ")
+                    ),
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks that the conditions can be parsed and only use placeholders, which are asked before.
+/// Computed placeholders are evaluated after all questions, so they can not be used in a condition.
+fn have_invalid_conditions(template: &TemplateConfiguration) -> Result<()> {
+    let computed_placeholders = template.get_computed_placeholders().map_err(Error::new)?;
+    let mut previous_keys: Vec<&String> = vec![];
+
+    for item in &template.templates {
+        for (key, placeholder) in &item.template_specification.placeholders {
+            let condition = placeholder.get_condition().map_err(|error| {
+                Error::new(format!(
+                    "Placeholder {} in {}: {}",
+                    key,
+                    item.root_path.display(),
+                    error
+                ))
+            })?;
+
+            let unknown_keys: Vec<String> = condition
+                .map(|condition| condition.get_placeholders())
+                .unwrap_or_default()
+                .into_iter()
+                .filter(|used_key| !previous_keys.contains(&used_key))
+                .collect();
+            if !unknown_keys.is_empty() {
+                return Err(Error::with_advice(
+                    format!(
+                        "The condition of placeholder {} in {} uses unknown placeholders: {}",
+                        key,
+                        item.root_path.display(),
+                        unknown_keys.join(", ")
+                    ),
+                    "A condition can only use placeholders, which are declared before it and are not computed".into(),
+                ));
+            }
+
+            if !computed_placeholders.contains_key(key) {
+                previous_keys.push(key);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks that the computed placeholders only use declared placeholders and do not depend on each other in a cycle.
+fn have_invalid_computed_placeholders(template: &TemplateConfiguration) -> Result<()> {
+    let computed_placeholders = template.get_computed_placeholders().map_err(Error::new)?;
+
+    for (key, expression) in &computed_placeholders {
+        let unknown_keys: Vec<String> = expression
+            .get_placeholders()
+            .into_iter()
+            .filter(|used_key| template.get_placeholder(used_key).is_none())
+            .collect();
+        if !unknown_keys.is_empty() {
+            return Err(Error::new(format!(
+                "The computed placeholder {} uses unknown placeholders: {}",
+                key,
+                unknown_keys.join(", ")
+            )));
+        }
+    }
+
+    sort_by_dependencies(&computed_placeholders).map_err(Error::new)?;
+
+    Ok(())
+}
+
+/// Checks that the glob patterns of `copy_without_render` and `keep_file_names` are valid.
+fn have_invalid_file_patterns(template: &TemplateConfiguration) -> Result<()> {
+    for item in &template.templates {
+        let specification = &item.template_specification;
+        if let Err(error) = specification
+            .get_copy_without_render()
+            .and(specification.get_keep_file_names())
+        {
+            return Err(Error::new(format!("{} in {}", error, item.root_path.display())));
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::templatespecification::core::template_configuration::TemplateConfigurationItem;
+    use crate::templatespecification::core::template_specification::TemplateSpecification;
+    use std::path::PathBuf;
+
+    fn create_template_configuration(placeholders: &str) -> TemplateConfiguration {
+        let specification: TemplateSpecification =
+            serde_yaml::from_str(&format!("placeholders:\n{}", placeholders)).unwrap();
+
+        let mut template_configuration = TemplateConfiguration::new();
+        template_configuration.templates.push(TemplateConfigurationItem::new(
+            PathBuf::from("creatorly.yml"),
+            specification,
+            vec![PathBuf::from("file.txt")],
+        ));
+
+        template_configuration
+    }
+
+    #[test]
+    fn test_validate_should_accept_valid_placeholders() {
+        let template = create_template_configuration(
+            "  name: Demo\n  port:\n    type: integer\n    default: 8080\n  license: [MIT, BSD]\n",
+        );
+
+        assert!(validate_template_configuration(&template).is_ok());
+    }
+
+    #[test]
+    fn test_validate_should_fail_for_choice_without_choices() {
+        let template = create_template_configuration("  license:\n    type: choice\n");
+
+        assert!(validate_template_configuration(&template).is_err());
+    }
+
+    #[test]
+    fn test_validate_should_fail_for_invalid_default() {
+        let template = create_template_configuration("  port:\n    type: integer\n    default: eighty\n");
+
+        assert!(validate_template_configuration(&template).is_err());
+    }
+
+    #[test]
+    fn test_validate_should_fail_for_invalid_pattern() {
+        let template = create_template_configuration("  name:\n    validation:\n      pattern: \"[a-z\"\n");
+
+        assert!(validate_template_configuration(&template).is_err());
+    }
+
+    #[test]
+    fn test_validate_should_accept_condition_on_previous_placeholder() {
+        let template = create_template_configuration(
+            "  ci_provider: [github, gitlab]
+  tag:
+    when: \"ci_provider == 'gitlab'\"
+",
+        );
+
+        assert!(validate_template_configuration(&template).is_ok());
+    }
+
+    #[test]
+    fn test_validate_should_fail_for_invalid_condition() {
+        let template = create_template_configuration(
+            "  tag:
+    when: \"ci_provider ==\"
+  ci_provider: gitlab
+",
+        );
+
+        assert!(validate_template_configuration(&template).is_err());
+    }
+
+    #[test]
+    fn test_validate_should_fail_for_condition_on_later_placeholder() {
+        let template = create_template_configuration(
+            "  tag:
+    when: \"ci_provider == 'gitlab'\"
+  ci_provider: gitlab
+",
+        );
+
+        assert!(validate_template_configuration(&template).is_err());
+    }
+
+    #[test]
+    fn test_validate_should_fail_for_condition_on_computed_placeholder() {
+        let template = create_template_configuration(
+            "  name: Demo\n  slug:\n    computed: \"{{ name | kebab }}\"\n  tag:\n    when: \"slug == 'demo'\"\n",
+        );
+
+        assert!(validate_template_configuration(&template).is_err());
+    }
+
+    #[test]
+    fn test_validate_should_fail_for_computed_placeholder_with_unknown_placeholder() {
+        let template = create_template_configuration("  slug:\n    computed: \"{{ name | kebab }}\"\n");
+
+        assert!(validate_template_configuration(&template).is_err());
+    }
+
+    #[test]
+    fn test_validate_should_fail_for_cycle_in_computed_placeholders() {
+        let template =
+            create_template_configuration("  a:\n    computed: \"{{ b }}\"\n  b:\n    computed: \"{{ a | upper }}\"\n");
+
+        let result = validate_template_configuration(&template);
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "The computed placeholders depend on each other: a -> b -> a"
+        );
+    }
+
+    #[test]
+    fn test_get_warnings_should_warn_about_prefix_keys() {
+        let template = create_template_configuration("  name: Max\n  name_full: Max Muster\n  city: Bern\n");
+
+        let warnings = get_template_configuration_warnings(&template);
+
+        assert_eq!(
+            warnings,
+            vec![
+                "Placeholder name is a prefix of placeholder name_full in creatorly.yml, CREATORLY.name_full is always replaced by the answer of name_full"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_get_warnings_should_ignore_jinja_templates() {
+        let mut template = create_template_configuration("  name: Max\n  name_full: Max Muster\n");
+        template.templates[0].template_specification =
+            serde_yaml::from_str("engine: jinja\nplaceholders:\n  name: Max\n  name_full: Max Muster\n").unwrap();
+
+        assert!(get_template_configuration_warnings(&template).is_empty());
+    }
+
+    #[test]
+    fn test_validate_should_fail_for_reserved_placeholder_keys() {
+        for placeholders in [
+            "  raw: text\n",
+            "  else-x: text\n",
+            "  raw-data: text\n",
+            "  include: text\n",
+        ] {
+            let template = create_template_configuration(placeholders);
+
+            assert!(validate_template_configuration(&template).is_err(), "{}", placeholders);
+        }
+
+        let template = create_template_configuration("  raw_data: text\n  if_used: text\n  format: text\n");
+        assert!(validate_template_configuration(&template).is_ok());
+    }
+
+    #[test]
+    fn test_validate_should_fail_for_invalid_file_patterns() {
+        let mut template = create_template_configuration("  name: Max\n");
+        template.templates[0].template_specification =
+            serde_yaml::from_str("copy_without_render: ['vendor/*.{js,sql']\nplaceholders:\n  name: Max\n").unwrap();
+
+        assert!(validate_template_configuration(&template).is_err());
+    }
+}
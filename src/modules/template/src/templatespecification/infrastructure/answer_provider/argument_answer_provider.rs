@@ -0,0 +1,88 @@
+use common::core::errors::{Error, Result};
+use indexmap::IndexMap;
+
+use crate::templatespecification::core::interfaces::AnswerProvider;
+
+/// Provides answers from command-line arguments in the form `key=value`.
+pub struct ArgumentAnswerProvider {
+    answers: IndexMap<String, String>,
+}
+
+impl ArgumentAnswerProvider {
+    /// Parses the given arguments. If a key is given multiple times, the last value wins.
+    pub fn from_arguments(arguments: &[String]) -> Result<Self> {
+        let mut answers = IndexMap::new();
+        for argument in arguments {
+            let Some((key, value)) = argument.split_once('=') else {
+                return Err(Error::with_advice(
+                    format!("Invalid answer argument: {}", argument),
+                    "Use the format key=value, for example --set project_name=foo".into(),
+                ));
+            };
+
+            let key = key.trim();
+            if key.is_empty() {
+                return Err(Error::with_advice(
+                    format!("Invalid answer argument: {}", argument),
+                    "The key must not be empty".into(),
+                ));
+            }
+
+            answers.insert(key.to_string(), value.to_string());
+        }
+
+        Ok(Self { answers })
+    }
+}
+
+impl AnswerProvider for ArgumentAnswerProvider {
+    fn get_source_name(&self) -> String {
+        "--set arguments".to_string()
+    }
+
+    fn get_answer(&self, key: &str) -> Option<String> {
+        self.answers.get(key).cloned()
+    }
+
+    fn get_keys(&self) -> Vec<String> {
+        self.answers.keys().cloned().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_arguments_should_parse_key_value_pairs() {
+        let arguments = vec![
+            "project_name=foo".to_string(),
+            "description=a=b".to_string(),
+            "project_name=bar".to_string(),
+        ];
+
+        let sut = ArgumentAnswerProvider::from_arguments(&arguments).unwrap();
+
+        assert_eq!(sut.get_keys(), vec!["project_name", "description"]);
+        assert_eq!(sut.get_answer("project_name"), Some("bar".to_string()));
+        assert_eq!(sut.get_answer("description"), Some("a=b".to_string()));
+    }
+
+    #[test]
+    fn test_from_arguments_should_return_error_without_delimiter() {
+        let arguments = vec!["project_name".to_string()];
+
+        let result = ArgumentAnswerProvider::from_arguments(&arguments);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_from_arguments_should_return_error_for_empty_key() {
+        let arguments = vec!["=foo".to_string()];
+
+        let result = ArgumentAnswerProvider::from_arguments(&arguments);
+
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,91 @@
+use std::collections::HashMap;
+
+use crate::templatespecification::core::interfaces::AnswerProvider;
+
+/// Provides answers from environment variables.
+///
+/// The name of the variable is the prefix followed by the placeholder in uppercase, where every character which
+/// is not alphanumeric is replaced by an underscore. For example `project_name` is read from
+/// `CREATORLY_ANSWER_PROJECT_NAME`.
+pub struct EnvAnswerProvider {
+    variables: HashMap<String, String>,
+}
+
+impl EnvAnswerProvider {
+    pub const PREFIX: &'static str = "CREATORLY_ANSWER_";
+
+    /// Creates a new provider from the given variables, only variables with the prefix are taken.
+    pub fn new(variables: impl IntoIterator<Item = (String, String)>) -> Self {
+        let variables = variables
+            .into_iter()
+            .filter(|(name, _)| name.starts_with(Self::PREFIX))
+            .collect();
+
+        Self { variables }
+    }
+
+    /// Creates a new provider from the environment variables of the current process.
+    pub fn from_env() -> Self {
+        Self::new(std::env::vars())
+    }
+
+    fn get_variable_name(key: &str) -> String {
+        let key: String = key
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() {
+                    c.to_ascii_uppercase()
+                } else {
+                    '_'
+                }
+            })
+            .collect();
+
+        format!("{}{}", Self::PREFIX, key)
+    }
+}
+
+impl AnswerProvider for EnvAnswerProvider {
+    fn get_source_name(&self) -> String {
+        format!("environment variables {}*", Self::PREFIX)
+    }
+
+    fn get_answer(&self, key: &str) -> Option<String> {
+        self.variables.get(&Self::get_variable_name(key)).cloned()
+    }
+
+    /// The environment is shared with other tools and templates, so the variables are not checked against the
+    /// declared placeholders.
+    fn get_keys(&self) -> Vec<String> {
+        vec![]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_answer_should_read_prefixed_variable() {
+        let variables = vec![
+            ("CREATORLY_ANSWER_PROJECT_NAME".to_string(), "MyProject".to_string()),
+            ("PROJECT_NAME".to_string(), "Other".to_string()),
+        ];
+
+        let sut = EnvAnswerProvider::new(variables);
+
+        assert_eq!(sut.get_answer("project_name"), Some("MyProject".to_string()));
+        assert_eq!(sut.get_answer("description"), None);
+        assert!(sut.get_keys().is_empty());
+    }
+
+    #[test]
+    fn test_get_answer_should_replace_non_alphanumeric_characters() {
+        let variables = vec![("CREATORLY_ANSWER_AUTHOR_NAME".to_string(), "Max".to_string())];
+
+        let sut = EnvAnswerProvider::new(variables);
+
+        assert_eq!(sut.get_answer("author-name"), Some("Max".to_string()));
+        assert_eq!(sut.get_answer("authorName"), None);
+    }
+}
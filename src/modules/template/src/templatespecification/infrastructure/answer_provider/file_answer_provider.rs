@@ -0,0 +1,138 @@
+use std::path::{Path, PathBuf};
+
+use common::core::errors::{Error, Result};
+use indexmap::IndexMap;
+use serde_yaml::Value;
+
+use crate::templatespecification::core::interfaces::AnswerProvider;
+
+/// Provides answers from a YAML or JSON file.
+///
+/// The file contains a map where the key is the placeholder and the value is the answer, for example:
+///
+/// ```yaml
+/// project_name: "MyProject"
+/// license: "MIT"
+/// services: ["web", "db"]
+/// ```
+pub struct FileAnswerProvider {
+    path: PathBuf,
+    answers: IndexMap<String, String>,
+}
+
+impl FileAnswerProvider {
+    /// Loads the answers from the given file. JSON is parsed as YAML, because it is a subset of it.
+    pub async fn load(path: &Path) -> Result<Self> {
+        let contents = tokio::fs::read_to_string(path)
+            .await
+            .map_err(|e| Error::new(format!("Unable to read answers file {}: {}", path.display(), e)))?;
+
+        let answers = Self::parse(&contents)
+            .map_err(|e| Error::new(format!("Unable to parse answers file {}: {}", path.display(), e)))?;
+
+        Ok(Self {
+            path: path.to_path_buf(),
+            answers,
+        })
+    }
+
+    fn parse(contents: &str) -> Result<IndexMap<String, String>> {
+        let parsed_value: IndexMap<String, Value> =
+            serde_yaml::from_str(contents).map_err(|e| Error::new(e.to_string()))?;
+
+        let mut answers = IndexMap::new();
+        for (key, value) in parsed_value {
+            let answer = match value {
+                Value::Sequence(values) => values
+                    .into_iter()
+                    .map(|value| Self::parse_scalar(&key, value))
+                    .collect::<Result<Vec<String>>>()?
+                    .join(", "),
+                value => Self::parse_scalar(&key, value)?,
+            };
+
+            answers.insert(key, answer);
+        }
+
+        Ok(answers)
+    }
+
+    fn parse_scalar(key: &str, value: Value) -> Result<String> {
+        match value {
+            Value::String(value) => Ok(value),
+            Value::Bool(value) => Ok(value.to_string()),
+            Value::Number(value) => Ok(value.to_string()),
+            _ => Err(Error::new(format!(
+                "unsupported answer for key {}, only strings, numbers, booleans and lists of them are allowed",
+                key
+            ))),
+        }
+    }
+}
+
+impl AnswerProvider for FileAnswerProvider {
+    fn get_source_name(&self) -> String {
+        format!("answers file {}", self.path.display())
+    }
+
+    fn get_answer(&self, key: &str) -> Option<String> {
+        self.answers.get(key).cloned()
+    }
+
+    fn get_keys(&self) -> Vec<String> {
+        self.answers.keys().cloned().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_load_should_read_yaml_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let file_path = dir.path().join("answers.yml");
+        tokio::fs::write(
+            &file_path,
+            "project_name: MyProject\nuse_docker: true\nport: 8080\nservices: [web, db]\n",
+        )
+        .await
+        .unwrap();
+
+        let sut = FileAnswerProvider::load(&file_path).await.unwrap();
+
+        assert_eq!(sut.get_keys(), vec!["project_name", "use_docker", "port", "services"]);
+        assert_eq!(sut.get_answer("project_name"), Some("MyProject".to_string()));
+        assert_eq!(sut.get_answer("use_docker"), Some("true".to_string()));
+        assert_eq!(sut.get_answer("port"), Some("8080".to_string()));
+        assert_eq!(sut.get_answer("services"), Some("web, db".to_string()));
+        assert_eq!(sut.get_answer("unknown"), None);
+    }
+
+    #[tokio::test]
+    async fn test_load_should_read_json_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let file_path = dir.path().join("answers.json");
+        tokio::fs::write(&file_path, r#"{ "project_name": "MyProject", "license": "MIT" }"#)
+            .await
+            .unwrap();
+
+        let sut = FileAnswerProvider::load(&file_path).await.unwrap();
+
+        assert_eq!(sut.get_answer("project_name"), Some("MyProject".to_string()));
+        assert_eq!(sut.get_answer("license"), Some("MIT".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_load_should_return_error_for_unsupported_value() {
+        let dir = tempfile::tempdir().unwrap();
+        let file_path = dir.path().join("answers.yml");
+        tokio::fs::write(&file_path, "project:\n  name: MyProject\n")
+            .await
+            .unwrap();
+
+        let result = FileAnswerProvider::load(&file_path).await;
+
+        assert!(result.is_err());
+    }
+}
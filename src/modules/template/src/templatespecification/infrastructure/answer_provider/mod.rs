@@ -0,0 +1,3 @@
+pub mod argument_answer_provider;
+pub mod env_answer_provider;
+pub mod file_answer_provider;
@@ -0,0 +1,93 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::templatespecification::core::answer::Answer;
+use crate::templatespecification::core::interfaces::{TemplateRenderer, UndefinedPlaceholder};
+use crate::templatespecification::core::template_specification::{RenderEngine, TemplateSpecification};
+use crate::templatespecification::infrastructure::jinja_templaterenderer::JinjaTemplateRenderer;
+use crate::templatespecification::infrastructure::regex_templaterenderer::RegexTemplateRenderer;
+use common::core::errors::Result;
+
+/// EngineTemplateRenderer is a struct that implements the TemplateRenderer trait.
+/// It renders the input with the renderer of the engine, which is configured in the template specification
+/// (`engine` in the creatorly.yml). So every template can use another engine.
+pub struct EngineTemplateRenderer {
+    regex_template_renderer: Arc<dyn TemplateRenderer>,
+    jinja_template_renderer: Arc<dyn TemplateRenderer>,
+}
+
+impl EngineTemplateRenderer {
+    pub fn new(
+        regex_template_renderer: Arc<dyn TemplateRenderer>,
+        jinja_template_renderer: Arc<dyn TemplateRenderer>,
+    ) -> Self {
+        Self {
+            regex_template_renderer,
+            jinja_template_renderer,
+        }
+    }
+}
+
+impl Default for EngineTemplateRenderer {
+    fn default() -> Self {
+        Self::new(
+            Arc::new(RegexTemplateRenderer::new()),
+            Arc::new(JinjaTemplateRenderer::new()),
+        )
+    }
+}
+
+impl TemplateRenderer for EngineTemplateRenderer {
+    fn render(&self, input: &str, config: &TemplateSpecification, answers: &HashMap<String, Answer>) -> Result<String> {
+        match config.get_engine() {
+            RenderEngine::Regex => self.regex_template_renderer.render(input, config, answers),
+            RenderEngine::Jinja => self.jinja_template_renderer.render(input, config, answers),
+        }
+    }
+
+    fn find_undefined_placeholders(
+        &self,
+        input: &str,
+        config: &TemplateSpecification,
+        answers: &HashMap<String, Answer>,
+    ) -> Result<Vec<UndefinedPlaceholder>> {
+        match config.get_engine() {
+            RenderEngine::Regex => self
+                .regex_template_renderer
+                .find_undefined_placeholders(input, config, answers),
+            RenderEngine::Jinja => self
+                .jinja_template_renderer
+                .find_undefined_placeholders(input, config, answers),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::templatespecification::core::interfaces::MockTemplateRenderer;
+
+    fn create_renderer(output: &'static str) -> Arc<dyn TemplateRenderer> {
+        let mut renderer = MockTemplateRenderer::new();
+        renderer
+            .expect_render()
+            .returning(move |_, _, _| Ok(output.to_string()));
+
+        Arc::new(renderer)
+    }
+
+    #[test]
+    fn render_should_use_configured_engine() {
+        let sut = EngineTemplateRenderer::new(create_renderer("regex"), create_renderer("jinja"));
+        let regex_specification = TemplateSpecification::new();
+        let jinja_specification: TemplateSpecification =
+            serde_yaml::from_str("engine: jinja\nplaceholders: {}\n").unwrap();
+
+        // act & assert
+        let output = sut.render("input", &regex_specification, &HashMap::new()).unwrap();
+        assert_eq!(output, "regex");
+
+        let output = sut.render("input", &jinja_specification, &HashMap::new()).unwrap();
+        assert_eq!(output, "jinja");
+    }
+}
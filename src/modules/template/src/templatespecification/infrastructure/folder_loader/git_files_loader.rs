@@ -79,6 +79,37 @@ impl GitFileListLoader {
         Ok(())
     }
 
+    /// Executes the `git rev-parse` command to get the commit of the cloned repository.
+    ///
+    /// # Arguments
+    ///
+    /// * `repository_path` - The path of the cloned repository.
+    ///
+    /// # Returns
+    ///
+    /// The hash of the checked out commit.
+    async fn execute_git_rev_parse(&self, repository_path: &Path) -> Result<String> {
+        let mut git_rev_parse_cmd = Command::new("git");
+        git_rev_parse_cmd
+            .arg("-C")
+            .arg(repository_path)
+            .arg("rev-parse")
+            .arg("HEAD");
+
+        let output = git_rev_parse_cmd
+            .output()
+            .await
+            .map_err(|_| Error::new("Failed to execute".into()))?;
+        if !output.status.success() {
+            return Err(Error::new(format!(
+                "git rev-parse failed: {}",
+                String::from_utf8_lossy(&output.stderr)
+            )));
+        }
+
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    }
+
     /// Tries to remove the cloned folder if it exists.
     ///
     /// # Arguments
@@ -132,6 +163,13 @@ impl FileListLoader for GitFileListLoader {
 
         Ok(filterd_files)
     }
+
+    async fn get_revision(&self, path: &Path) -> Result<Option<String>> {
+        let download_path = path.join(self.get_git_name());
+        let commit = self.execute_git_rev_parse(&download_path).await?;
+
+        Ok(Some(commit))
+    }
 }
 
 #[cfg(test)]
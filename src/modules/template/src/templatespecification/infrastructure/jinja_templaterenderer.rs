@@ -0,0 +1,160 @@
+use std::collections::HashMap;
+
+use crate::templatespecification::core::answer::Answer;
+use crate::templatespecification::core::filters::{apply_filter, FILTER_NAMES};
+use crate::templatespecification::core::interfaces::{TemplateRenderer, UndefinedPlaceholder};
+use crate::templatespecification::core::template_specification::TemplateSpecification;
+use common::core::errors::{Error, Result};
+use minijinja::{Environment, ErrorKind, Value};
+
+/// JinjaTemplateRenderer is a struct that implements the TemplateRenderer trait.
+/// It renders the input as a Jinja template with `{{ project_name }}`, `{% if use_docker %}` and
+/// `{% for service in services %}`. The answers of multi choices and lists are lists, the answers `true` and `false`
+/// are booleans.
+///
+/// The filters of creatorly (`snake`, `kebab`, `pascal`, `camel`, `upper`, `lower` and `title`) can be used,
+/// for example `{{ project_name | snake }}`.
+pub struct JinjaTemplateRenderer {
+    environment: Environment<'static>,
+}
+
+impl JinjaTemplateRenderer {
+    pub fn new() -> Self {
+        let mut environment = Environment::new();
+        environment.set_keep_trailing_newline(true);
+
+        for filter in FILTER_NAMES {
+            environment.add_filter(filter, move |value: String| {
+                apply_filter(filter, &value).map_err(|e| minijinja::Error::new(ErrorKind::InvalidOperation, e))
+            });
+        }
+
+        Self { environment }
+    }
+}
+
+impl Default for JinjaTemplateRenderer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TemplateRenderer for JinjaTemplateRenderer {
+    fn render(
+        &self,
+        input: &str,
+        _config: &TemplateSpecification,
+        answers: &HashMap<String, Answer>,
+    ) -> Result<String> {
+        let context: HashMap<&str, Value> = answers
+            .iter()
+            .map(|(key, answer)| {
+                let value = match answer {
+                    Answer::Single(value) if value == "true" => Value::from(true),
+                    Answer::Single(value) if value == "false" => Value::from(false),
+                    answer => Value::from_serialize(answer),
+                };
+
+                (key.as_str(), value)
+            })
+            .collect();
+
+        self.environment
+            .render_str(input, context)
+            .map_err(|e| Error::new(format!("Error rendering jinja template: {}", e)))
+    }
+
+    /// Returns the variables of the template, which are neither answers nor globals of Jinja.
+    /// The line number is unknown, because Jinja does not provide it for variables.
+    fn find_undefined_placeholders(
+        &self,
+        input: &str,
+        _config: &TemplateSpecification,
+        answers: &HashMap<String, Answer>,
+    ) -> Result<Vec<UndefinedPlaceholder>> {
+        let template = self
+            .environment
+            .template_from_str(input)
+            .map_err(|e| Error::new(format!("Error parsing jinja template: {}", e)))?;
+
+        let mut variables: Vec<String> = template
+            .undeclared_variables(false)
+            .into_iter()
+            .filter(|variable| !answers.contains_key(variable))
+            .filter(|variable| self.environment.globals().all(|(name, _)| name != variable))
+            .collect();
+        variables.sort();
+
+        Ok(variables
+            .into_iter()
+            .map(|variable| UndefinedPlaceholder {
+                line_number: None,
+                placeholder: variable,
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_answers() -> HashMap<String, Answer> {
+        HashMap::from([
+            ("project_name".to_string(), Answer::from("My Project")),
+            ("use_docker".to_string(), Answer::from("true")),
+            ("use_ci".to_string(), Answer::from("false")),
+            (
+                "services".to_string(),
+                Answer::List(vec!["web".to_string(), "db".to_string()]),
+            ),
+        ])
+    }
+
+    #[test]
+    fn render_should_render_jinja_template() {
+        let sut = JinjaTemplateRenderer::new();
+        let input = "name: {{ project_name | snake }}\n\
+                     {% if use_docker %}docker: yes\n{% endif %}{% if use_ci %}ci: yes\n{% endif %}\
+                     {% for service in services %}- {{ service | upper }}\n{% endfor %}";
+
+        // act
+        let output = sut
+            .render(input, &TemplateSpecification::new(), &create_answers())
+            .unwrap();
+
+        // assert
+        assert_eq!(output, "name: my_project\ndocker: yes\n- WEB\n- DB\n");
+    }
+
+    #[test]
+    fn render_should_return_error_for_invalid_template() {
+        let sut = JinjaTemplateRenderer::new();
+
+        // act
+        let result = sut.render("{% if use_docker %}", &TemplateSpecification::new(), &create_answers());
+
+        // assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn find_undefined_placeholders_should_return_unknown_variables() {
+        let sut = JinjaTemplateRenderer::new();
+        let input =
+            "{{ projekt_name }} {{ project_name }}\n{% for service in services %}{{ service }}{{ port }}{% endfor %}\n\
+                     {% for i in range(2) %}{{ loop.index }}{% endfor %}";
+
+        // act
+        let undefined_placeholders = sut
+            .find_undefined_placeholders(input, &TemplateSpecification::new(), &create_answers())
+            .unwrap();
+
+        // assert
+        let placeholders: Vec<&str> = undefined_placeholders
+            .iter()
+            .map(|undefined_placeholder| undefined_placeholder.placeholder.as_str())
+            .collect();
+        assert_eq!(placeholders, vec!["port", "projekt_name"]);
+    }
+}
@@ -1,3 +1,7 @@
+pub mod answer_provider;
 pub mod configuration_loader;
+pub mod engine_templaterenderer;
 pub mod folder_loader;
+pub mod jinja_templaterenderer;
 pub mod regex_templaterenderer;
+pub mod user_configuration_loader;
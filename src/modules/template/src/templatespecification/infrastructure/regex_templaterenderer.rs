@@ -1,50 +1,563 @@
 use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
 
-use crate::templatespecification::core::{interfaces::TemplateRenderer, template_specification::TemplateSpecification};
+use crate::templatespecification::core::answer::Answer;
+use crate::templatespecification::core::condition::is_true;
+use crate::templatespecification::core::filters::{apply_filter, FILTER_NAMES};
+use crate::templatespecification::core::interfaces::{TemplateRenderer, UndefinedPlaceholder};
+use crate::templatespecification::core::template_specification::TemplateSpecification;
 use common::core::errors::{Error, Result};
-use regex::Regex;
+use regex::{Captures, Regex};
 
 /// RegexTemplateRenderer is a struct that implements the TemplateRenderer trait.
 /// It is responsible for rendering the template by replacing the placeholders with the answers.
 /// It uses the regex method to replace the placeholders with the answers.
-pub struct RegexTemplateRenderer {}
+///
+/// A placeholder can be followed by filters, for example `CREATORLY.project_name|snake|upper`.
+/// The filters are applied from left to right, unknown filters are not part of the placeholder. A filter name has to
+/// end at a word boundary, so `CREATORLY.name|lowercase` is not read as the filter `lower`.
+/// If keys overlap, like `name` and `name_full`, the longest key is replaced.
+///
+/// Sections of the input can be included depending on the answers or repeated for each entry of a list with block
+/// tags, which can be nested:
+///
+/// ```text
+/// CREATORLY.if.use_docker
+/// docker is used
+/// CREATORLY.else
+/// docker is not used
+/// CREATORLY.endif
+/// CREATORLY.for.service in services
+/// service CREATORLY.service
+/// CREATORLY.endfor
+/// ```
+///
+/// The condition is a placeholder, which is true if it is not empty and not `false`, or a comparison like
+/// `CREATORLY.if.ci_provider==gitlab` or `CREATORLY.if.ci_provider!=gitlab`. A line with a block tag is removed
+/// completely, so the tag can be written in a comment of the file.
+///
+/// Literal placeholder text is written between the lines `CREATORLY.raw` and `CREATORLY.endraw`, in a file which starts
+/// with a line containing `CREATORLY.rawfile` or with a backslash (`\CREATORLY.project_name`), if `placeholder_escape` is
+/// enabled. Otherwise a backslash before a placeholder is kept, like in the Windows path `src\CREATORLY.project_name`.
+///
+/// Every line is substituted exactly once, so answers and loop entries, which contain placeholder text, are written as
+/// they are. The matchers are compiled once per placeholder id, delimiter and set of answers and reused for every file.
+#[derive(Default)]
+pub struct RegexTemplateRenderer {
+    matchers: Mutex<HashMap<MatcherKey, Arc<Regex>>>,
+}
 
-impl TemplateRenderer for RegexTemplateRenderer {
-    fn render(&self, input: &str, config: &TemplateSpecification, answers: &HashMap<String, String>) -> Result<String> {
-        let mut output = input.to_string();
+/// The kind of a matcher, the placeholder id, the delimiter, whether escapes are enabled and the keys of the
+/// placeholders, which it matches.
+type MatcherKey = (MatcherKind, String, String, bool, Vec<String>);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum MatcherKind {
+    /// Matches the block tags.
+    Tag,
+    /// Matches every text, which looks like a placeholder.
+    Token,
+    /// Matches the escaped prefix and the placeholders, which have an answer.
+    Placeholder,
+}
+
+impl RegexTemplateRenderer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the matcher of the block tags for the placeholder id and delimiter of the specification.
+    fn get_tag_matcher(&self, config: &TemplateSpecification) -> Result<Arc<Regex>> {
+        let key = create_matcher_key(MatcherKind::Tag, config, vec![]);
+
+        self.get_matcher(key, |(_, placeholder_id, delimiter, is_escape_enabled, _)| {
+            let prefix = regex::escape(&format!("{}{}", placeholder_id, delimiter));
+            let delimiter = regex::escape(delimiter);
+            format!(
+                r"{}{prefix}(?:if{delimiter}(?P<key>[\w-]+)(?:(?P<operator>==|!=)(?P<value>[\w.-]+))?|for{delimiter}(?P<variable>[\w-]+)\s+in\s+(?P<list_key>[\w-]+)|(?P<else>else)\b|(?P<end>endif|endfor)\b|(?P<raw>raw)\b|(?P<endraw>endraw)\b|(?P<rawfile>rawfile)\b)",
+                get_escape_pattern(*is_escape_enabled)
+            )
+        })
+    }
+
+    /// Returns the matcher of all placeholders, which have an answer, with their filters and of the escaped prefix
+    /// (for example `\CREATORLY.`), if escapes are enabled.
+    fn get_placeholder_matcher(
+        &self,
+        config: &TemplateSpecification,
+        answers: &HashMap<String, Answer>,
+    ) -> Result<Arc<Regex>> {
+        // the longest key comes first, so that the alternation matches `name_full` before `name`
+        let mut keys: Vec<String> = answers.keys().cloned().collect();
+        keys.sort_by(|a, b| b.len().cmp(&a.len()).then_with(|| a.cmp(b)));
+        let key = create_matcher_key(MatcherKind::Placeholder, config, keys);
+
+        self.get_matcher(key, |(_, placeholder_id, delimiter, is_escape_enabled, keys)| {
+            let prefix = regex::escape(&format!("{}{}", placeholder_id, delimiter));
+            let mut alternatives = vec![];
+            if *is_escape_enabled {
+                alternatives.push(format!(r"\\(?P<escape>{})", prefix));
+            }
+
+            if !keys.is_empty() {
+                let keys: Vec<String> = keys.iter().map(|key| regex::escape(key)).collect();
+                alternatives.push(format!(
+                    r"{}(?P<key>{})(?P<filters>(?:\|(?:{})\b)*)",
+                    prefix,
+                    keys.join("|"),
+                    FILTER_NAMES.join("|")
+                ));
+            }
+
+            // an empty class never matches, if there is nothing to replace
+            if alternatives.is_empty() {
+                return r"[^\s\S]".to_string();
+            }
+
+            alternatives.join("|")
+        })
+    }
+
+    /// Returns the matcher of every text, which looks like a placeholder, also if it has no answer.
+    fn get_token_matcher(&self, config: &TemplateSpecification) -> Result<Arc<Regex>> {
+        let key = create_matcher_key(MatcherKind::Token, config, vec![]);
+
+        self.get_matcher(key, |(_, placeholder_id, delimiter, is_escape_enabled, _)| {
+            let prefix = regex::escape(&format!("{}{}", placeholder_id, delimiter));
+            format!(
+                r"{}{}(?P<identifier>[\w-]+)",
+                get_escape_pattern(*is_escape_enabled),
+                prefix
+            )
+        })
+    }
 
-        for (key, answer) in answers.iter() {
-            let replacable_string = format!(
-                "{}{}{}",
-                config.get_placeholder_id(),
-                config.get_placeholder_delimiter(),
-                key
-            );
+    /// Returns the cached matcher for the key or compiles and caches it with the pattern of the key.
+    fn get_matcher(&self, key: MatcherKey, create_pattern: impl FnOnce(&MatcherKey) -> String) -> Result<Arc<Regex>> {
+        let mut matchers = self
+            .matchers
+            .lock()
+            .map_err(|_| Error::new("The matchers of the renderer are not available".to_string()))?;
 
-            let regex = Regex::new(&regex::escape(&replacable_string).to_string())
-                .map_err(|e| Error::new(format!("Error creating regex: {}", e)))?;
+        if let Some(matcher) = matchers.get(&key) {
+            return Ok(matcher.clone());
+        }
+
+        let matcher =
+            Regex::new(&create_pattern(&key)).map_err(|e| Error::new(format!("Error creating regex: {}", e)))?;
+        let matcher = Arc::new(matcher);
+        matchers.insert(key, matcher.clone());
+
+        Ok(matcher)
+    }
+
+    /// Keeps, removes or repeats the sections of the blocks and replaces the placeholders of the kept lines.
+    /// The lines of raw blocks and raw files are written as they are.
+    fn render_blocks(
+        &self,
+        input: &str,
+        config: &TemplateSpecification,
+        answers: &HashMap<String, Answer>,
+    ) -> Result<String> {
+        let tag_regex = self.get_tag_matcher(config)?;
+        let placeholder_matcher = self.get_placeholder_matcher(config, answers)?;
 
-            output = regex.replace_all(&output, answer.as_str()).to_string();
+        if !tag_regex.is_match(input) {
+            return Ok(replace_placeholders(input, &placeholder_matcher, answers));
         }
 
+        let (first_line, rest) = input.split_once('\n').unwrap_or((input, ""));
+        if get_tags(first_line, &tag_regex).any(|tag| tag.name("rawfile").is_some()) {
+            return Ok(rest.to_string());
+        }
+
+        let nodes = parse_blocks(input, &tag_regex)?;
+        let mut output = String::new();
+        self.render_nodes(&nodes, config, answers, &placeholder_matcher, &mut output)?;
+
         Ok(output)
     }
+
+    /// Renders the parsed blocks and replaces the placeholders of their lines with the matcher of the answers.
+    /// The body of a loop is rendered for each entry with the loop variable as an additional answer, its matcher is
+    /// looked up once per loop.
+    fn render_nodes(
+        &self,
+        nodes: &[Node],
+        config: &TemplateSpecification,
+        answers: &HashMap<String, Answer>,
+        placeholder_matcher: &Regex,
+        output: &mut String,
+    ) -> Result<()> {
+        for node in nodes {
+            let block = match node {
+                Node::Line(line) => {
+                    output.push_str(&replace_placeholders(line, placeholder_matcher, answers));
+                    continue;
+                }
+                Node::RawLine(line) => {
+                    output.push_str(line);
+                    continue;
+                }
+                Node::Block(block) => block,
+            };
+
+            let get_answer = |key: &str| {
+                answers.get(key).ok_or_else(|| {
+                    Error::new(format!(
+                        "Line {}: unknown placeholder {} in {}",
+                        block.line_number, key, block.tag
+                    ))
+                })
+            };
+
+            match &block.kind {
+                BlockKind::If { key, comparison } => {
+                    let answer = get_answer(key)?.to_string();
+                    let is_fulfilled = match comparison {
+                        Some((operator, value)) if operator == "==" => answer == *value,
+                        Some((_, value)) => answer != *value,
+                        None => is_true(&answer),
+                    };
+
+                    if is_fulfilled {
+                        self.render_nodes(&block.nodes, config, answers, placeholder_matcher, output)?;
+                    } else if let Some(else_nodes) = &block.else_nodes {
+                        self.render_nodes(else_nodes, config, answers, placeholder_matcher, output)?;
+                    }
+                }
+                BlockKind::For { variable, list_key } => {
+                    let entries = get_answer(list_key)?.get_entries();
+
+                    // the keys are the same for every entry, so the matcher of the loop is looked up once
+                    let mut loop_answers = answers.clone();
+                    loop_answers.insert(variable.clone(), Answer::from(""));
+                    let loop_matcher = self.get_placeholder_matcher(config, &loop_answers)?;
+                    for entry in entries {
+                        loop_answers.insert(variable.clone(), Answer::Single(entry));
+
+                        self.render_nodes(&block.nodes, config, &loop_answers, &loop_matcher, output)?;
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl TemplateRenderer for RegexTemplateRenderer {
+    fn render(&self, input: &str, config: &TemplateSpecification, answers: &HashMap<String, Answer>) -> Result<String> {
+        self.render_blocks(input, config, answers)
+    }
+
+    /// Returns the placeholders, which are not replaced, because their identifier does not start with the key of an
+    /// answer or a loop variable. Like for rendering, a suffix after the key is text, so `CREATORLY.name_test` and
+    /// `CREATORLY.name-cli` are defined, if `name` has an answer.
+    /// Escaped placeholders, block tags and the text of raw blocks and raw files are ignored.
+    fn find_undefined_placeholders(
+        &self,
+        input: &str,
+        config: &TemplateSpecification,
+        answers: &HashMap<String, Answer>,
+    ) -> Result<Vec<UndefinedPlaceholder>> {
+        let tag_regex = self.get_tag_matcher(config)?;
+        let token_regex = self.get_token_matcher(config)?;
+
+        // the loop variables are matched like answers with the same longest-first matcher as for rendering
+        let mut defined_answers = answers.clone();
+        for tag in tag_regex.captures_iter(input) {
+            if let Some(variable) = tag.name("variable") {
+                defined_answers.insert(variable.as_str().to_string(), Answer::from(""));
+            }
+        }
+
+        let placeholder_matcher = self.get_placeholder_matcher(config, &defined_answers)?;
+        // the placeholder starts with the prefix, so a match at its start is a key and not an escape
+        let is_defined = |placeholder: &str| {
+            placeholder_matcher
+                .find(placeholder)
+                .is_some_and(|found| found.start() == 0)
+        };
+
+        let mut undefined_placeholders = vec![];
+        let mut is_raw = false;
+        for (index, line) in input.lines().enumerate() {
+            let tags: Vec<Captures> = get_tags(line, &tag_regex).collect();
+            if index == 0 && tags.iter().any(|tag| tag.name("rawfile").is_some()) {
+                return Ok(vec![]);
+            }
+
+            if tags.iter().any(|tag| tag.name("raw").is_some()) {
+                is_raw = true;
+            } else if tags.iter().any(|tag| tag.name("endraw").is_some()) {
+                is_raw = false;
+            }
+
+            if is_raw {
+                continue;
+            }
+
+            let tag_ranges: Vec<_> = tag_regex.find_iter(line).map(|tag| tag.range()).collect();
+            for token in token_regex.captures_iter(line) {
+                let token_range = token.get(0).expect("the whole match exists").range();
+                let is_tag = tag_ranges
+                    .iter()
+                    .any(|range| range.start < token_range.end && token_range.start < range.end);
+                let placeholder = format!(
+                    "{}{}{}",
+                    config.get_placeholder_id(),
+                    config.get_placeholder_delimiter(),
+                    &token["identifier"]
+                );
+                if token.name("escape").is_some() || is_tag || is_defined(&placeholder) {
+                    continue;
+                }
+
+                undefined_placeholders.push(UndefinedPlaceholder {
+                    line_number: Some(index + 1),
+                    placeholder,
+                });
+            }
+        }
+
+        Ok(undefined_placeholders)
+    }
+}
+
+/// A part of the input, which is a line, a line which is not rendered or a block with its nested parts.
+enum Node<'a> {
+    Line(&'a str),
+    RawLine(&'a str),
+    Block(Block<'a>),
+}
+
+/// A block, which is opened by an `if` or a `for` tag.
+struct Block<'a> {
+    line_number: usize,
+    tag: String,
+    kind: BlockKind,
+    nodes: Vec<Node<'a>>,
+    else_nodes: Option<Vec<Node<'a>>>,
+}
+
+enum BlockKind {
+    If {
+        key: String,
+        comparison: Option<(String, String)>,
+    },
+    For {
+        variable: String,
+        list_key: String,
+    },
+}
+
+impl BlockKind {
+    fn get_end_tag(&self) -> &'static str {
+        match self {
+            Self::If { .. } => "endif",
+            Self::For { .. } => "endfor",
+        }
+    }
+}
+
+/// Returns the key of a matcher for the placeholder id, the delimiter and the escapes of the specification.
+fn create_matcher_key(kind: MatcherKind, config: &TemplateSpecification, keys: Vec<String>) -> MatcherKey {
+    (
+        kind,
+        config.get_placeholder_id(),
+        config.get_placeholder_delimiter(),
+        config.get_placeholder_escape(),
+        keys,
+    )
+}
+
+/// Returns the optional backslash before a tag or a placeholder, which escapes it, if escapes are enabled.
+fn get_escape_pattern(is_escape_enabled: bool) -> &'static str {
+    match is_escape_enabled {
+        true => r"(?P<escape>\\)?",
+        false => "",
+    }
+}
+
+/// Replaces the placeholders with the answers in one pass and applies their filters. The matcher has to be created for
+/// the keys of the answers. An escaped prefix is written without the backslash and an answer, which contains placeholder
+/// text, is not replaced again.
+fn replace_placeholders(input: &str, placeholder_matcher: &Regex, answers: &HashMap<String, Answer>) -> String {
+    let output = placeholder_matcher.replace_all(input, |captures: &Captures| {
+        if let Some(prefix) = captures.name("escape") {
+            return prefix.as_str().to_string();
+        }
+
+        let answer = answers[&captures["key"]].to_string();
+        captures["filters"]
+            .split('|')
+            .filter(|filter| !filter.is_empty())
+            .fold(answer, |value, filter| {
+                apply_filter(filter, &value).expect("the regex only matches known filters")
+            })
+    });
+
+    output.to_string()
+}
+
+/// Returns the block tags of the line, which are not escaped.
+fn get_tags<'a>(line: &'a str, tag_regex: &'a Regex) -> impl Iterator<Item = Captures<'a>> {
+    tag_regex.captures_iter(line).filter(|tag| tag.name("escape").is_none())
+}
+
+/// Returns the nodes, where the next node is added. It is the innermost open block or the top level.
+fn get_current_nodes<'a, 'b>(open_blocks: &'b mut [Block<'a>], nodes: &'b mut Vec<Node<'a>>) -> &'b mut Vec<Node<'a>> {
+    match open_blocks.last_mut() {
+        Some(block) => block.else_nodes.as_mut().unwrap_or(&mut block.nodes),
+        None => nodes,
+    }
+}
+
+/// Parses the lines of the input into blocks.
+fn parse_blocks<'a>(input: &'a str, tag_regex: &Regex) -> Result<Vec<Node<'a>>> {
+    let mut nodes = vec![];
+    let mut open_blocks: Vec<Block> = vec![];
+    let mut open_raw_block: Option<(usize, String)> = None;
+
+    for (index, line) in input.split_inclusive('\n').enumerate() {
+        let line_number = index + 1;
+        let tags: Vec<Captures> = get_tags(line, tag_regex).collect();
+
+        if open_raw_block.is_some() {
+            if tags.iter().any(|tag| tag.name("endraw").is_some()) {
+                open_raw_block = None;
+            } else {
+                get_current_nodes(&mut open_blocks, &mut nodes).push(Node::RawLine(line));
+            }
+
+            continue;
+        }
+
+        let tag = match tags.as_slice() {
+            [] => {
+                get_current_nodes(&mut open_blocks, &mut nodes).push(Node::Line(line));
+                continue;
+            }
+            [tag] => tag,
+            _ => {
+                return Err(Error::new(format!(
+                    "Line {}: only one block tag per line is allowed",
+                    line_number
+                )))
+            }
+        };
+
+        if tag.name("raw").is_some() {
+            open_raw_block = Some((line_number, tag[0].to_string()));
+            continue;
+        }
+
+        if tag.name("endraw").is_some() {
+            return Err(Error::new(format!(
+                "Line {}: {} without an open raw",
+                line_number, &tag[0]
+            )));
+        }
+
+        if tag.name("rawfile").is_some() {
+            return Err(Error::new(format!(
+                "Line {}: {} is only allowed in the first line",
+                line_number, &tag[0]
+            )));
+        }
+
+        let kind = if let Some(key) = tag.name("key") {
+            let comparison = tag
+                .name("operator")
+                .zip(tag.name("value"))
+                .map(|(operator, value)| (operator.as_str().to_string(), value.as_str().to_string()));
+
+            Some(BlockKind::If {
+                key: key.as_str().to_string(),
+                comparison,
+            })
+        } else {
+            tag.name("variable")
+                .zip(tag.name("list_key"))
+                .map(|(variable, list_key)| BlockKind::For {
+                    variable: variable.as_str().to_string(),
+                    list_key: list_key.as_str().to_string(),
+                })
+        };
+
+        if let Some(kind) = kind {
+            open_blocks.push(Block {
+                line_number,
+                tag: tag[0].to_string(),
+                kind,
+                nodes: vec![],
+                else_nodes: None,
+            });
+        } else if tag.name("else").is_some() {
+            match open_blocks.last_mut() {
+                Some(block) if block.else_nodes.is_some() => {
+                    return Err(Error::new(format!(
+                        "Line {}: {} after another else of {}",
+                        line_number, &tag[0], block.tag
+                    )))
+                }
+                Some(block) if matches!(block.kind, BlockKind::If { .. }) => block.else_nodes = Some(vec![]),
+                _ => {
+                    return Err(Error::new(format!(
+                        "Line {}: {} without an open if",
+                        line_number, &tag[0]
+                    )))
+                }
+            }
+        } else {
+            let Some(block) = open_blocks.pop() else {
+                return Err(Error::new(format!(
+                    "Line {}: {} without an open block",
+                    line_number, &tag[0]
+                )));
+            };
+
+            if !tag[0].ends_with(block.kind.get_end_tag()) {
+                return Err(Error::new(format!(
+                    "Line {}: {} does not close {} of line {}",
+                    line_number, &tag[0], block.tag, block.line_number
+                )));
+            }
+
+            get_current_nodes(&mut open_blocks, &mut nodes).push(Node::Block(block));
+        }
+    }
+
+    if let Some((line_number, tag)) = open_raw_block {
+        return Err(Error::new(format!("Line {}: {} is not closed", line_number, tag)));
+    }
+
+    if let Some(block) = open_blocks.last() {
+        return Err(Error::new(format!(
+            "Line {}: {} is not closed",
+            block.line_number, block.tag
+        )));
+    }
+
+    Ok(nodes)
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::templatespecification::core::answer::Answer;
     use crate::templatespecification::core::template_specification::TemplateSpecificationItemType;
 
     use super::*;
 
     #[test]
     fn render_should_return_rendered_value() {
-        let sut = RegexTemplateRenderer {};
+        let sut = RegexTemplateRenderer::new();
         let mut data = TemplateSpecification::new();
         let mut answers = HashMap::new();
         let template_item = TemplateSpecificationItemType::SingleChoice("Max".to_string());
         data.placeholders.insert("name".to_string(), template_item);
-        answers.insert("name".to_string(), "Max".to_string());
+        answers.insert("name".to_string(), Answer::from("Max"));
 
         // act
         let output = sut.render("Hello CREATORLY.name!", &data, &answers).unwrap();
@@ -56,12 +569,12 @@ mod tests {
 
     #[test]
     fn render_should_not_render_when_delimeter_is_underscore_but_placeholder_is_dot() {
-        let sut = RegexTemplateRenderer {};
+        let sut = RegexTemplateRenderer::new();
         let mut data = TemplateSpecification::from_id_delimiter("CREATORLY".to_string(), "_".to_string());
         let mut answers = HashMap::new();
         let template_item = TemplateSpecificationItemType::SingleChoice("Max".to_string());
         data.placeholders.insert("name".to_string(), template_item);
-        answers.insert("name".to_string(), "Max".to_string());
+        answers.insert("name".to_string(), Answer::from("Max"));
 
         // act
         let output = sut.render("Hello CREATORLY.name_test!", &data, &answers).unwrap();
@@ -73,16 +586,16 @@ mod tests {
 
     #[test]
     fn render_should_render_both_template() {
-        let sut = RegexTemplateRenderer {};
+        let sut = RegexTemplateRenderer::new();
         let mut data = TemplateSpecification::new();
         let mut answers = HashMap::new();
         let template_item = TemplateSpecificationItemType::SingleChoice("Max".to_string());
         data.placeholders.insert("name".to_string(), template_item);
-        answers.insert("name".to_string(), "Max".to_string());
+        answers.insert("name".to_string(), Answer::from("Max"));
 
         let template_item = TemplateSpecificationItemType::SingleChoice("30".to_string());
         data.placeholders.insert("age".to_string(), template_item);
-        answers.insert("age".to_string(), "30".to_string());
+        answers.insert("age".to_string(), Answer::from("30"));
 
         // act
         let output = sut
@@ -98,7 +611,7 @@ mod tests {
 
     #[test]
     fn render_should_return_input_if_no_render_data_match() {
-        let sut = RegexTemplateRenderer {};
+        let sut = RegexTemplateRenderer::new();
         let mut data = TemplateSpecification::new();
         let answers = HashMap::new();
         data.placeholders.insert(
@@ -111,7 +624,7 @@ mod tests {
 
     #[test]
     fn render_should_not_render_if_template_is_not_write_correctly() {
-        let sut = RegexTemplateRenderer {};
+        let sut = RegexTemplateRenderer::new();
         let mut data = TemplateSpecification::new();
         let answers = HashMap::new();
         data.placeholders.insert(
@@ -126,12 +639,12 @@ mod tests {
 
     #[test]
     fn render_should_render_with_custom_placeholder_id_and_placeholder_delimeter() {
-        let sut = RegexTemplateRenderer {};
+        let sut = RegexTemplateRenderer::new();
         let mut data = TemplateSpecification::from_id_delimiter("creatorly".to_string(), "-".to_string());
         let mut answers = HashMap::new();
         let item = TemplateSpecificationItemType::SingleChoice("Max".to_string());
         data.placeholders.insert("name".to_string(), item);
-        answers.insert("name".to_string(), "Max".to_string());
+        answers.insert("name".to_string(), Answer::from("Max"));
 
         // act & assert
         let output1 = sut.render("Hello CREATORLY.name!", &data, &answers).unwrap();
@@ -143,12 +656,12 @@ mod tests {
 
     #[test]
     fn render_should_render_with_klab_placeholder_id_and_placeholder_delimeter() {
-        let sut = RegexTemplateRenderer {};
+        let sut = RegexTemplateRenderer::new();
         let mut data = TemplateSpecification::from_id_delimiter("KlabTestFramework".to_string(), ".".to_string());
         let mut answers = HashMap::new();
         let item = TemplateSpecificationItemType::SingleChoice("Max".to_string());
         data.placeholders.insert("ProjectName".to_string(), item);
-        answers.insert("ProjectName".to_string(), "SuperDuper".to_string());
+        answers.insert("ProjectName".to_string(), Answer::from("SuperDuper"));
 
         // act & assert
         let output1 = sut
@@ -159,12 +672,12 @@ mod tests {
 
     #[test]
     fn render_should_return_path() {
-        let sut = RegexTemplateRenderer {};
+        let sut = RegexTemplateRenderer::new();
         let mut data = TemplateSpecification::from_id_delimiter("creatorly".to_string(), ".".to_string());
         let mut answers = HashMap::new();
         let item = TemplateSpecificationItemType::SingleChoice("Max".to_string());
         data.placeholders.insert("name".to_string(), item);
-        answers.insert("name".to_string(), "superduper".to_string());
+        answers.insert("name".to_string(), Answer::from("superduper"));
 
         // act & assert
         let output1 = sut
@@ -172,4 +685,492 @@ mod tests {
             .unwrap();
         assert_eq!(output1, "/core/superduper/docs/hello.txt");
     }
+
+    #[test]
+    fn render_should_apply_filters() {
+        let sut = RegexTemplateRenderer::new();
+        let mut data = TemplateSpecification::new();
+        let mut answers = HashMap::new();
+        let item = TemplateSpecificationItemType::SingleChoice("Max".to_string());
+        data.placeholders.insert("project_name".to_string(), item);
+        answers.insert("project_name".to_string(), Answer::from("My Project"));
+
+        // act
+        let output = sut
+            .render(
+                "CREATORLY.project_name|snake CREATORLY.project_name|kebab CREATORLY.project_name|pascal|upper",
+                &data,
+                &answers,
+            )
+            .unwrap();
+
+        // assert
+        assert_eq!(output, "my_project my-project MYPROJECT");
+    }
+
+    #[test]
+    fn render_should_apply_filters_with_custom_placeholder_id_and_placeholder_delimeter() {
+        let sut = RegexTemplateRenderer::new();
+        let mut data = TemplateSpecification::from_id_delimiter("creatorly".to_string(), "-".to_string());
+        let mut answers = HashMap::new();
+        let item = TemplateSpecificationItemType::SingleChoice("Max".to_string());
+        data.placeholders.insert("name".to_string(), item);
+        answers.insert("name".to_string(), Answer::from("My Project"));
+
+        // act
+        let output = sut.render("/src/creatorly-name|snake.rs", &data, &answers).unwrap();
+
+        // assert
+        assert_eq!(output, "/src/my_project.rs");
+    }
+
+    #[test]
+    fn render_should_keep_unknown_filters() {
+        let sut = RegexTemplateRenderer::new();
+        let mut data = TemplateSpecification::new();
+        let mut answers = HashMap::new();
+        let item = TemplateSpecificationItemType::SingleChoice("Max".to_string());
+        data.placeholders.insert("name".to_string(), item);
+        answers.insert("name".to_string(), Answer::from("Max"));
+
+        // act
+        let output = sut
+            .render("| CREATORLY.name| CREATORLY.name|reverse |", &data, &answers)
+            .unwrap();
+
+        // assert
+        assert_eq!(output, "| Max| Max|reverse |");
+    }
+
+    #[test]
+    fn render_should_not_apply_filters_which_are_the_prefix_of_a_word() {
+        let sut = RegexTemplateRenderer::new();
+        let mut data = TemplateSpecification::new();
+        let mut answers = HashMap::new();
+        let item = TemplateSpecificationItemType::SingleChoice("Max".to_string());
+        data.placeholders.insert("name".to_string(), item);
+        answers.insert("name".to_string(), Answer::from("Max"));
+
+        // act
+        let output = sut
+            .render(
+                "CREATORLY.name|lowercase CREATORLY.name|upper|lowercase CREATORLY.name|lower-case",
+                &data,
+                &answers,
+            )
+            .unwrap();
+
+        // assert
+        assert_eq!(output, "Max|lowercase MAX|lowercase max-case");
+    }
+
+    #[test]
+    fn render_should_replace_longest_overlapping_key() {
+        let sut = RegexTemplateRenderer::new();
+        let data = TemplateSpecification::new();
+        let answers = HashMap::from([
+            ("name".to_string(), Answer::from("Max")),
+            ("name_full".to_string(), Answer::from("Max Muster")),
+            ("name_full_upper".to_string(), Answer::from("MAX MUSTER")),
+        ]);
+
+        // act
+        let output = sut
+            .render(
+                "CREATORLY.name, CREATORLY.name_full, CREATORLY.name_full_upper, CREATORLY.name_first",
+                &data,
+                &answers,
+            )
+            .unwrap();
+
+        // assert
+        assert_eq!(output, "Max, Max Muster, MAX MUSTER, Max_first");
+    }
+
+    #[test]
+    fn find_undefined_placeholders_should_return_placeholders_without_answer() {
+        let sut = RegexTemplateRenderer::new();
+        let data = create_escape_specification();
+        let input = "CREATORLY.projekt_name\nCREATORLY.use_docker_image \\CREATORLY.escaped\n\
+                     CREATORLY.if.use_ci\nCREATORLY.endif\n\
+                     CREATORLY.for.service in services\nCREATORLY.service|upper CREATORLY.port\nCREATORLY.endfor\n\
+                     CREATORLY.raw\nCREATORLY.literal\nCREATORLY.endraw";
+
+        // act
+        let undefined_placeholders = sut
+            .find_undefined_placeholders(input, &data, &create_block_answers())
+            .unwrap();
+
+        // assert
+        assert_eq!(
+            undefined_placeholders,
+            vec![
+                UndefinedPlaceholder {
+                    line_number: Some(1),
+                    placeholder: "CREATORLY.projekt_name".to_string(),
+                },
+                UndefinedPlaceholder {
+                    line_number: Some(6),
+                    placeholder: "CREATORLY.port".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn find_undefined_placeholders_should_accept_suffixes_after_defined_keys() {
+        let sut = RegexTemplateRenderer::new();
+        let data = TemplateSpecification::new();
+        let answers = HashMap::from([("name".to_string(), Answer::from("Max"))]);
+        let input = "CREATORLY.name-cli CREATORLY.name_test CREATORLY.other_name";
+
+        // act
+        let output = sut.render(input, &data, &answers).unwrap();
+        let undefined_placeholders = sut.find_undefined_placeholders(input, &data, &answers).unwrap();
+
+        // assert
+        assert_eq!(output, "Max-cli Max_test CREATORLY.other_name");
+        assert_eq!(
+            undefined_placeholders,
+            vec![UndefinedPlaceholder {
+                line_number: Some(1),
+                placeholder: "CREATORLY.other_name".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn find_undefined_placeholders_should_ignore_raw_files() {
+        let sut = RegexTemplateRenderer::new();
+        let data = TemplateSpecification::new();
+
+        // act
+        let undefined_placeholders = sut
+            .find_undefined_placeholders("# CREATORLY.rawfile\nCREATORLY.literal", &data, &HashMap::new())
+            .unwrap();
+
+        // assert
+        assert!(undefined_placeholders.is_empty());
+    }
+
+    #[test]
+    fn render_should_not_replace_placeholders_in_answers() {
+        let sut = RegexTemplateRenderer::new();
+        let data = TemplateSpecification::new();
+        let answers = HashMap::from([
+            ("name".to_string(), Answer::from("CREATORLY.age")),
+            ("age".to_string(), Answer::from("30")),
+        ]);
+
+        // act
+        let output = sut.render("CREATORLY.name is CREATORLY.age", &data, &answers).unwrap();
+
+        // assert
+        assert_eq!(output, "CREATORLY.age is 30");
+    }
+
+    #[test]
+    fn render_should_not_replace_placeholders_in_answers_and_loop_entries() {
+        let sut = RegexTemplateRenderer::new();
+        let data = TemplateSpecification::new();
+        let answers = HashMap::from([
+            ("name".to_string(), Answer::from("Max")),
+            ("title".to_string(), Answer::from("CREATORLY.name")),
+            (
+                "entries".to_string(),
+                Answer::List(vec!["CREATORLY.name".to_string(), "CREATORLY.title".to_string()]),
+            ),
+        ]);
+        let input = "CREATORLY.title\nCREATORLY.if.name\nCREATORLY.for.entry in entries\n\
+                     CREATORLY.entry CREATORLY.name\nCREATORLY.endfor\nCREATORLY.endif\n";
+
+        // act
+        let output = sut.render(input, &data, &answers).unwrap();
+
+        // assert
+        assert_eq!(output, "CREATORLY.name\nCREATORLY.name Max\nCREATORLY.title Max\n");
+    }
+
+    #[test]
+    fn render_should_reuse_matchers_for_same_answers() {
+        let sut = RegexTemplateRenderer::new();
+        let data = TemplateSpecification::new();
+        let answers = HashMap::from([("name".to_string(), Answer::from("Max"))]);
+
+        // act
+        let first_output = sut.render("Hello CREATORLY.name", &data, &answers).unwrap();
+        let second_output = sut.render("Bye CREATORLY.name", &data, &answers).unwrap();
+
+        // assert
+        assert_eq!(first_output, "Hello Max");
+        assert_eq!(second_output, "Bye Max");
+        assert_eq!(sut.matchers.lock().unwrap().len(), 2);
+    }
+
+    /// creates a specification, where a backslash before a placeholder writes it literally
+    fn create_escape_specification() -> TemplateSpecification {
+        serde_yaml::from_str("placeholder_escape: true\nplaceholders: {}\n").unwrap()
+    }
+
+    #[test]
+    fn render_should_keep_backslash_before_placeholders_without_placeholder_escape() {
+        let sut = RegexTemplateRenderer::new();
+        let data = TemplateSpecification::new();
+        let answers = HashMap::from([("ProjectName".to_string(), Answer::from("Demo"))]);
+
+        // act
+        let output = sut
+            .render(
+                "src\\CREATORLY.ProjectName\\CREATORLY.ProjectName.csproj",
+                &data,
+                &answers,
+            )
+            .unwrap();
+
+        // assert
+        assert_eq!(output, "src\\Demo\\Demo.csproj");
+    }
+
+    fn create_block_answers() -> HashMap<String, Answer> {
+        HashMap::from([
+            ("use_docker".to_string(), Answer::from("true")),
+            ("use_ci".to_string(), Answer::from("false")),
+            ("ci_provider".to_string(), Answer::from("gitlab")),
+            (
+                "services".to_string(),
+                Answer::List(vec!["web".to_string(), "db".to_string()]),
+            ),
+            ("no_services".to_string(), Answer::List(vec![])),
+        ])
+    }
+
+    #[test]
+    fn render_should_keep_sections_of_fulfilled_blocks() {
+        let sut = RegexTemplateRenderer::new();
+        let data = TemplateSpecification::new();
+        let input = "start\n# CREATORLY.if.use_docker\ndocker\n# CREATORLY.else\nno docker\n# CREATORLY.endif\n\
+                     CREATORLY.if.use_ci\nci\nCREATORLY.else\nno ci\nCREATORLY.endif\nend";
+
+        // act
+        let output = sut.render(input, &data, &create_block_answers()).unwrap();
+
+        // assert
+        assert_eq!(output, "start\ndocker\nno ci\nend");
+    }
+
+    #[test]
+    fn render_should_support_nested_blocks_and_comparisons() {
+        let sut = RegexTemplateRenderer::new();
+        let data = TemplateSpecification::new();
+        let input = "CREATORLY.if.use_docker\n\
+                     CREATORLY.if.ci_provider==gitlab\nimage: CREATORLY.ci_provider\nCREATORLY.endif\n\
+                     CREATORLY.if.ci_provider!=gitlab\nruns-on: ubuntu\nCREATORLY.endif\n\
+                     CREATORLY.endif\n\
+                     CREATORLY.if.use_ci\nCREATORLY.if.use_docker\nhidden\nCREATORLY.else\nhidden\nCREATORLY.endif\nCREATORLY.endif\n";
+
+        // act
+        let output = sut.render(input, &data, &create_block_answers()).unwrap();
+
+        // assert
+        assert_eq!(output, "image: gitlab\n");
+    }
+
+    #[test]
+    fn render_should_support_blocks_with_custom_placeholder_id_and_placeholder_delimeter() {
+        let sut = RegexTemplateRenderer::new();
+        let data = TemplateSpecification::from_id_delimiter("creatorly".to_string(), "-".to_string());
+
+        // act
+        let output = sut
+            .render(
+                "creatorly-if-use_ci\nci\ncreatorly-else\nno ci\ncreatorly-endif\n",
+                &data,
+                &create_block_answers(),
+            )
+            .unwrap();
+
+        // assert
+        assert_eq!(output, "no ci\n");
+    }
+
+    #[test]
+    fn render_should_return_error_for_unbalanced_blocks() {
+        let sut = RegexTemplateRenderer::new();
+        let data = TemplateSpecification::new();
+        let answers = create_block_answers();
+
+        let not_closed = sut.render("a\nCREATORLY.if.use_docker\nb\n", &data, &answers);
+        let not_opened = sut.render("a\nb\nCREATORLY.endif\n", &data, &answers);
+        let second_else = sut.render(
+            "CREATORLY.if.use_ci\nCREATORLY.else\nCREATORLY.else\nCREATORLY.endif",
+            &data,
+            &answers,
+        );
+        let unknown_key = sut.render("CREATORLY.if.unknown\nCREATORLY.endif", &data, &answers);
+
+        assert_eq!(
+            not_closed.unwrap_err().to_string(),
+            "Line 2: CREATORLY.if.use_docker is not closed"
+        );
+        assert_eq!(
+            not_opened.unwrap_err().to_string(),
+            "Line 3: CREATORLY.endif without an open block"
+        );
+        assert_eq!(
+            second_else.unwrap_err().to_string(),
+            "Line 3: CREATORLY.else after another else of CREATORLY.if.use_ci"
+        );
+        assert!(unknown_key.is_err());
+    }
+
+    #[test]
+    fn render_should_repeat_loop_for_each_entry() {
+        let sut = RegexTemplateRenderer::new();
+        let data = TemplateSpecification::new();
+        let input = "services:\n\
+                     # CREATORLY.for.service in services\n  CREATORLY.service:\n    image: CREATORLY.service|upper\n\
+                     CREATORLY.if.service==db\n    volume: data\nCREATORLY.endif\n# CREATORLY.endfor\n\
+                     CREATORLY.for.service in no_services\n  CREATORLY.service\nCREATORLY.endfor\nci: CREATORLY.ci_provider";
+
+        // act
+        let output = sut.render(input, &data, &create_block_answers()).unwrap();
+
+        // assert
+        assert_eq!(
+            output,
+            "services:\n  web:\n    image: WEB\n  db:\n    image: DB\n    volume: data\nci: gitlab"
+        );
+    }
+
+    #[test]
+    fn render_should_not_replace_loop_variable_in_longer_keys() {
+        let sut = RegexTemplateRenderer::new();
+        let data = TemplateSpecification::new();
+        let mut answers = create_block_answers();
+        answers.insert("name_full".to_string(), Answer::from("Max Muster"));
+        answers.insert(
+            "names".to_string(),
+            Answer::List(vec!["web".to_string(), "db".to_string()]),
+        );
+        let input = "CREATORLY.for.service in services\nCREATORLY.service: CREATORLY.services\nCREATORLY.endfor\n\
+                     CREATORLY.for.name in names\nCREATORLY.name: CREATORLY.name_full\nCREATORLY.endfor\n";
+
+        // act
+        let output = sut.render(input, &data, &answers).unwrap();
+
+        // assert
+        assert_eq!(output, "web: web, db\ndb: web, db\nweb: Max Muster\ndb: Max Muster\n");
+    }
+
+    #[test]
+    fn render_should_return_error_for_mismatched_loop() {
+        let sut = RegexTemplateRenderer::new();
+        let data = TemplateSpecification::new();
+        let answers = create_block_answers();
+
+        let mismatched = sut.render("CREATORLY.for.service in services\nCREATORLY.endif\n", &data, &answers);
+        let else_in_loop = sut.render("CREATORLY.for.service in services\nCREATORLY.else\n", &data, &answers);
+        let unknown_list = sut.render("CREATORLY.for.service in unknown\nCREATORLY.endfor\n", &data, &answers);
+
+        assert_eq!(
+            mismatched.unwrap_err().to_string(),
+            "Line 2: CREATORLY.endif does not close CREATORLY.for.service in services of line 1"
+        );
+        assert_eq!(
+            else_in_loop.unwrap_err().to_string(),
+            "Line 2: CREATORLY.else without an open if"
+        );
+        assert_eq!(
+            unknown_list.unwrap_err().to_string(),
+            "Line 1: unknown placeholder unknown in CREATORLY.for.service in unknown"
+        );
+    }
+
+    #[test]
+    fn render_should_not_render_escaped_placeholders() {
+        let sut = RegexTemplateRenderer::new();
+        let data = create_escape_specification();
+        let input = "\\CREATORLY.ci_provider|upper is CREATORLY.ci_provider\n\\CREATORLY.if.use_ci\nend";
+
+        // act
+        let output = sut.render(input, &data, &create_block_answers()).unwrap();
+
+        // assert
+        assert_eq!(
+            output,
+            "CREATORLY.ci_provider|upper is gitlab\nCREATORLY.if.use_ci\nend"
+        );
+    }
+
+    #[test]
+    fn render_should_not_render_raw_blocks() {
+        let sut = RegexTemplateRenderer::new();
+        let data = TemplateSpecification::new();
+        let input = "CREATORLY.for.service in services\nCREATORLY.raw\nCREATORLY.service CREATORLY.if.use_ci\n\
+                     CREATORLY.endraw\nCREATORLY.service\nCREATORLY.endfor\n";
+
+        // act
+        let output = sut.render(input, &data, &create_block_answers()).unwrap();
+
+        // assert
+        assert_eq!(
+            output,
+            "CREATORLY.service CREATORLY.if.use_ci\nweb\nCREATORLY.service CREATORLY.if.use_ci\ndb\n"
+        );
+    }
+
+    #[test]
+    fn render_should_keep_private_use_characters() {
+        let sut = RegexTemplateRenderer::new();
+        let data = create_escape_specification();
+        let input =
+            "\u{E000}7\u{E001} \\CREATORLY.ci_provider CREATORLY.ci_provider\nCREATORLY.raw\n\u{E000}0\u{E001}\n\
+                     CREATORLY.endraw\n\u{E000}0\u{E001}";
+
+        // act
+        let output = sut.render(input, &data, &create_block_answers()).unwrap();
+
+        // assert
+        assert_eq!(
+            output,
+            "\u{E000}7\u{E001} CREATORLY.ci_provider gitlab\n\u{E000}0\u{E001}\n\u{E000}0\u{E001}"
+        );
+    }
+
+    #[test]
+    fn render_should_copy_raw_file_verbatim() {
+        let sut = RegexTemplateRenderer::new();
+        let data = TemplateSpecification::new();
+        let input = "<!-- CREATORLY.rawfile -->\nCREATORLY.ci_provider\nCREATORLY.if.unknown\n";
+
+        // act
+        let output = sut.render(input, &data, &create_block_answers()).unwrap();
+
+        // assert
+        assert_eq!(output, "CREATORLY.ci_provider\nCREATORLY.if.unknown\n");
+    }
+
+    #[test]
+    fn render_should_return_error_for_unbalanced_raw_blocks() {
+        let sut = RegexTemplateRenderer::new();
+        let data = TemplateSpecification::new();
+        let answers = create_block_answers();
+
+        let not_closed = sut.render("a\nCREATORLY.raw\nb\n", &data, &answers);
+        let not_opened = sut.render("a\nCREATORLY.endraw\n", &data, &answers);
+        let raw_file = sut.render("a\nCREATORLY.rawfile\n", &data, &answers);
+
+        assert_eq!(
+            not_closed.unwrap_err().to_string(),
+            "Line 2: CREATORLY.raw is not closed"
+        );
+        assert_eq!(
+            not_opened.unwrap_err().to_string(),
+            "Line 2: CREATORLY.endraw without an open raw"
+        );
+        assert_eq!(
+            raw_file.unwrap_err().to_string(),
+            "Line 2: CREATORLY.rawfile is only allowed in the first line"
+        );
+    }
 }
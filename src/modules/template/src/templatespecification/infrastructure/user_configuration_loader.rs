@@ -0,0 +1,103 @@
+use std::path::{Path, PathBuf};
+
+use common::core::errors::{Error, Result};
+
+use crate::templatespecification::core::user_configuration::UserConfiguration;
+
+/// Loads the configuration of the user from `$XDG_CONFIG_HOME/creatorly/config.yml`.
+/// If `XDG_CONFIG_HOME` is not set, `~/.config/creatorly/config.yml` is used.
+#[derive(Default)]
+pub struct YamlUserConfigurationLoader {}
+
+impl YamlUserConfigurationLoader {
+    /// Returns the path of the configuration file of the current user.
+    pub fn get_configuration_path(&self) -> Option<PathBuf> {
+        get_configuration_path(std::env::var("XDG_CONFIG_HOME").ok(), std::env::var("HOME").ok())
+    }
+
+    /// Loads the configuration of the current user, if there is no configuration file the default is returned.
+    pub async fn load_user_configuration(&self) -> Result<UserConfiguration> {
+        let Some(configuration_path) = self.get_configuration_path() else {
+            return Ok(UserConfiguration::default());
+        };
+
+        self.load_configuration(&configuration_path).await
+    }
+
+    /// Loads the configuration from the given path, if the file does not exist the default is returned.
+    pub async fn load_configuration(&self, configuration_path: &Path) -> Result<UserConfiguration> {
+        if !configuration_path.is_file() {
+            return Ok(UserConfiguration::default());
+        }
+
+        let contents = tokio::fs::read_to_string(configuration_path)
+            .await
+            .map_err(|e| Error::new(format!("Unable to read {}: {}", configuration_path.display(), e)))?;
+
+        if contents.trim().is_empty() {
+            return Ok(UserConfiguration::default());
+        }
+
+        serde_yaml::from_str(&contents)
+            .map_err(|e| Error::new(format!("Unable to parse {}: {}", configuration_path.display(), e)))
+    }
+}
+
+/// Returns the path of the configuration file, a relative `XDG_CONFIG_HOME` is ignored as the spec requires.
+fn get_configuration_path(xdg_config_home: Option<String>, home: Option<String>) -> Option<PathBuf> {
+    let config_home = xdg_config_home
+        .map(PathBuf::from)
+        .filter(|path| path.is_absolute())
+        .or_else(|| home.map(|home| PathBuf::from(home).join(".config")))?;
+
+    Some(config_home.join("creatorly").join("config.yml"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_configuration_path_should_prefer_xdg_config_home() {
+        let path = get_configuration_path(Some("/xdg".to_string()), Some("/home/max".to_string()));
+
+        assert_eq!(path, Some(PathBuf::from("/xdg/creatorly/config.yml")));
+    }
+
+    #[test]
+    fn test_get_configuration_path_should_fallback_to_home() {
+        let path = get_configuration_path(Some("relative".to_string()), Some("/home/max".to_string()));
+        assert_eq!(path, Some(PathBuf::from("/home/max/.config/creatorly/config.yml")));
+
+        let path = get_configuration_path(None, None);
+        assert_eq!(path, None);
+    }
+
+    #[tokio::test]
+    async fn test_load_configuration_should_read_default_answers() {
+        let dir = tempfile::tempdir().unwrap();
+        let file_path = dir.path().join("config.yml");
+        tokio::fs::write(
+            &file_path,
+            "default_answers:\n  author_name: Jane Doe\n  author_email: jane@example.com\n",
+        )
+        .await
+        .unwrap();
+        let sut = YamlUserConfigurationLoader::default();
+
+        let configuration = sut.load_configuration(&file_path).await.unwrap();
+
+        assert_eq!(configuration.default_answers["author_name"], "Jane Doe");
+        assert_eq!(configuration.default_answers["author_email"], "jane@example.com");
+    }
+
+    #[tokio::test]
+    async fn test_load_configuration_should_return_default_if_file_does_not_exist() {
+        let dir = tempfile::tempdir().unwrap();
+        let sut = YamlUserConfigurationLoader::default();
+
+        let configuration = sut.load_configuration(&dir.path().join("config.yml")).await.unwrap();
+
+        assert_eq!(configuration, UserConfiguration::default());
+    }
+}